@@ -24,6 +24,71 @@ pub trait StrRead {
     fn is_empty(&self) -> bool {
         self.peek_str().is_none()
     }
+
+    /// Get the bounds on how many more `&str`s can be popped, like [`Iterator::size_hint`].
+    ///
+    /// By default, this only knows whether the reader is empty.
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        if self.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, None)
+        }
+    }
+
+    /// Check if the next `&str` is equal to `pat`.
+    ///
+    /// Returns `false` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StrRead, StringReader, StringWrite};
+    ///
+    /// let mut sread: StringReader = StringReader::new();
+    /// assert!(!sread.next_is("let"));
+    /// sread.push_string("let".to_string());
+    /// assert!(sread.next_is("let"));
+    /// assert!(!sread.next_is("le"));
+    /// ```
+    fn next_is(&self, pat: &str) -> bool {
+        self.peek_str() == Some(pat)
+    }
+
+    /// Check if the next `&str` starts with `prefix`.
+    ///
+    /// Returns `false` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StrRead, StringReader, StringWrite};
+    ///
+    /// let mut sread: StringReader = StringReader::new();
+    /// assert!(!sread.next_starts_with("le"));
+    /// sread.push_string("let".to_string());
+    /// assert!(sread.next_starts_with("le"));
+    /// assert!(!sread.next_starts_with("fn"));
+    /// ```
+    fn next_starts_with(&self, prefix: &str) -> bool {
+        self.peek_str().is_some_and(|s| s.starts_with(prefix))
+    }
+
+    /// Check if the next `&str` is equal to `other`, ignoring ASCII case.
+    ///
+    /// Non-ASCII characters must match exactly; for Unicode-aware matching, compare the
+    /// lowercased strings instead. Returns `false` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let sread: StringReader = StringReader::from("SELECT".to_string());
+    /// assert!(sread.next_eq_ignore_ascii_case("select"));
+    /// assert!(!sread.next_eq_ignore_ascii_case("insert"));
+    /// ```
+    fn next_eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.peek_str()
+            .is_some_and(|s| s.eq_ignore_ascii_case(other))
+    }
 }
 
 /// Represent anything that pops out `&str`.
@@ -55,14 +120,16 @@ pub trait StrWrite<'a> {
     ///
     /// # Examples
     /// ```rust
-    /// let sread = StrReader::default();
+    /// use string_reader::{RealStrRead, StrReader, StrWrite};
+    ///
+    /// let mut sread: StrReader = StrReader::default();
     /// sread.push_str("hai");
     /// sread.push_str("bai");
     /// assert_eq!(sread.pop_str(), Some("hai"));
     /// assert_eq!(sread.pop_str(), Some("bai"));
     /// assert_eq!(sread.pop_str(), None);
     /// ```
-    fn push_str(&'a mut self, s: &'a str);
+    fn push_str(&mut self, s: &'a str);
 
     /// Insert a `&str` into the reader.
     ///
@@ -70,14 +137,16 @@ pub trait StrWrite<'a> {
     ///
     /// # Examples
     /// ```rust
-    /// let sread = StrReader::default();
+    /// use string_reader::{RealStrRead, StrReader, StrWrite};
+    ///
+    /// let mut sread: StrReader = StrReader::default();
     /// sread.shift_str("hai");
     /// sread.shift_str("bai");
     /// assert_eq!(sread.pop_str(), Some("bai"));
     /// assert_eq!(sread.pop_str(), Some("hai"));
     /// assert_eq!(sread.pop_str(), None);
     /// ```
-    fn shift_str(&'a mut self, s: &'a str);
+    fn shift_str(&mut self, s: &'a str);
 }
 
 /// Write/insert operations with `String`-type readers.
@@ -88,7 +157,9 @@ pub trait StringWrite {
     ///
     /// # Examples
     /// ```rust
-    /// let sread = StringReader::default();
+    /// use string_reader::{StringRead, StringReader, StringWrite};
+    ///
+    /// let mut sread: StringReader = StringReader::default();
     /// sread.push_string("hai".to_string());
     /// sread.push_string("bai".to_string());
     /// assert_eq!(sread.pop_string(), Some("hai".to_string()));
@@ -98,13 +169,15 @@ pub trait StringWrite {
     fn push_string(&mut self, s: String);
     /// Insert a `String` into the reader.
     ///
-    /// The newly inserted `String` will be the *last* item in the list.
+    /// The newly inserted `String` will be the *next* item to be returned.
     ///
     /// # Examples
     /// ```rust
-    /// let sread = StringReader::default();
+    /// use string_reader::{StringRead, StringReader, StringWrite};
+    ///
+    /// let mut sread: StringReader = StringReader::default();
     /// sread.shift_string("hai".to_string());
-    /// sread.shift_string"bai".to_string());
+    /// sread.shift_string("bai".to_string());
     /// assert_eq!(sread.pop_string(), Some("bai".to_string()));
     /// assert_eq!(sread.pop_string(), Some("hai".to_string()));
     /// assert_eq!(sread.pop_string(), None);
@@ -114,7 +187,12 @@ pub trait StringWrite {
 
 impl StrRead for String {
     fn peek_str(&self) -> Option<&str> {
-        Some(self)
+        (!self.is_empty()).then_some(self)
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(!self.is_empty());
+        (len, Some(len))
     }
 
     // fn map_str(&mut self, mut f: impl FnMut(&mut str)) {
@@ -126,16 +204,32 @@ impl StrRead for String {
     // }
 }
 impl StringRead for String {
+    /// Take the whole `String`, leaving an empty one behind.
+    ///
+    /// An empty `String` counts as exhausted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StrRead, StringRead};
+    ///
+    /// let mut s = "hai".to_string();
+    /// assert_eq!(s.pop_string(), Some("hai".to_string()));
+    /// assert_eq!(s.pop_string(), None);
+    /// assert!(s.is_empty());
+    /// s.map_string(|_| unreachable!());
+    /// ```
     fn pop_string(&mut self) -> Option<String> {
-        Some(std::mem::take(self))
+        (!self.is_empty()).then(|| std::mem::take(self))
     }
 
     fn map_string(&mut self, mut f: impl FnMut(&mut String)) {
-        f(self);
+        if !self.is_empty() {
+            f(self);
+        }
     }
 
     fn peek_mut_string(&mut self) -> Option<&mut String> {
-        Some(self)
+        (!self.is_empty()).then_some(self)
     }
 }
 
@@ -146,6 +240,10 @@ impl StringRead for String {
 pub struct StringReader<R: StringRead = String> {
     pub queue: VecDeque<String>,
     pub reader: Option<R>,
+    stats: ConsumptionStats,
+    max_bytes: Option<usize>,
+    /// How many bytes of the front character [`std::io::Read`] already returned.
+    split: usize,
 }
 
 impl<R: StringRead> Default for StringReader<R> {
@@ -153,6 +251,9 @@ impl<R: StringRead> Default for StringReader<R> {
         Self {
             queue: Default::default(),
             reader: None,
+            stats: ConsumptionStats::default(),
+            max_bytes: None,
+            split: 0,
         }
     }
 }
@@ -162,6 +263,9 @@ impl<R: StringRead> From<R> for StringReader<R> {
         Self {
             queue: Default::default(),
             reader: Some(value),
+            stats: ConsumptionStats::default(),
+            max_bytes: None,
+            split: 0,
         }
     }
 }
@@ -171,185 +275,3181 @@ impl<R: StringRead> From<VecDeque<String>> for StringReader<R> {
         Self {
             queue: value,
             reader: None,
+            stats: ConsumptionStats::default(),
+            max_bytes: None,
+            split: 0,
         }
     }
 }
 
-impl<R: StringRead> StringReader<R> {
-    /// Equivalent to `default()`.
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+impl<R: StringRead> From<char> for StringReader<R> {
+    /// Create a reader with the character as its only segment.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread = StringReader::<String>::from('x');
+    /// assert_eq!(sread.pop_string(), Some("x".to_string()));
+    /// assert_eq!(sread.pop_string(), None);
+    /// ```
+    fn from(value: char) -> Self {
+        VecDeque::from([value.to_string()]).into()
     }
 }
 
-impl<R: StringRead> StrRead for StringReader<R> {
-    fn peek_str(&self) -> Option<&str> {
-        (self.queue.front().map(|s| s.as_str()))
-            .or_else(|| self.reader.as_ref().map(|r| r.peek_str())?)
+impl<R: StringRead> From<&[char]> for StringReader<R> {
+    /// Create a reader with the concatenated characters as its only segment.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread = StringReader::<String>::from(&['a', 'b', 'c'][..]);
+    /// assert_eq!(sread.pop_string(), Some("abc".to_string()));
+    /// assert_eq!(sread.pop_string(), None);
+    /// ```
+    fn from(value: &[char]) -> Self {
+        VecDeque::from([value.iter().collect::<String>()]).into()
     }
+}
 
-    // fn peek_mut_str<'a>(&'a mut self) -> Option<&'a mut str> {
-    //     (self.stack.last_mut().map(|s| s.as_mut_str()))
-    //         .or_else(|| self.reader.as_mut().map(|r| r.peek_mut_str())?)
-    // }
+impl<R: StringRead> TryFrom<&[u8]> for StringReader<R> {
+    type Error = std::str::Utf8Error;
 
-    fn is_empty(&self) -> bool {
-        self.queue.is_empty() && self.reader.as_ref().map_or(true, |r| r.is_empty())
+    /// Create a reader with the bytes as its only segment, if they are valid UTF-8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread = StringReader::<String>::try_from(&b"hai"[..]).unwrap();
+    /// assert_eq!(sread.pop_string(), Some("hai".to_string()));
+    /// assert!(StringReader::<String>::try_from(&b"\xffhai"[..]).is_err());
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(VecDeque::from([std::str::from_utf8(value)?.to_string()]).into())
     }
 }
 
-impl<R: StringRead> StringRead for StringReader<R> {
-    fn pop_string(&mut self) -> Option<String> {
-        (self.queue.pop_front()).or_else(|| self.reader.as_mut().map(|r| r.pop_string())?)
-    }
-
-    fn peek_mut_string(&mut self) -> Option<&mut String> {
-        (self.queue.front_mut()).or_else(|| self.reader.as_mut().map(|r| r.peek_mut_string())?)
+impl<R: StringRead> AsRef<VecDeque<String>> for StringReader<R> {
+    fn as_ref(&self) -> &VecDeque<String> {
+        &self.queue
     }
 }
 
-impl<R: StringRead> std::io::Read for StringReader<R> {
-    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let mut l = buf.len();
-        let mut pos = 0;
-        while let Some(s) = self.peek_mut_string() {
-            let slen = s.len();
-            if slen > l {
-                buf[pos..].copy_from_slice(s[..l].as_bytes());
-                *s = s[l..].to_string();
-                return Ok(buf.len());
-            }
-            // slen <= l
-            buf[pos..pos + slen].copy_from_slice(self.pop_string().unwrap().as_bytes());
-            pos += slen;
-            l -= slen;
-        }
-        Ok(pos)
+impl<R: StringRead> StringReader<R> {
+    /// Equivalent to `default()`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
     }
-}
 
-impl<R: StringRead> std::io::BufRead for StringReader<R> {
-    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
-        if let Some(s) = self.peek_str() {
-            Ok(s.as_bytes())
-        } else if let Some(s) = self.reader.as_ref().and_then(|r| r.peek_str()) {
-            Ok(s.as_bytes())
-        } else {
-            Ok(&[])
+    /// Create a reader out of a queue and an optional backing reader.
+    ///
+    /// This is the inverse of [`StringReader::into_parts`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from("c".to_string());
+    /// sread.queue = VecDeque::from(["a", "b"].map(String::from));
+    /// let (queue, reader) = sread.into_parts();
+    /// assert_eq!(queue, ["a", "b"]);
+    /// assert_eq!(reader.as_deref(), Some("c"));
+    /// let mut sread: StringReader = StringReader::from_parts(queue, reader);
+    /// let popped: Vec<String> = std::iter::from_fn(|| sread.pop_string()).collect();
+    /// assert_eq!(popped, ["a", "b", "c"]);
+    /// ```
+    #[must_use]
+    pub fn from_parts(queue: VecDeque<String>, reader: Option<R>) -> Self {
+        Self {
+            queue,
+            reader,
+            stats: ConsumptionStats::default(),
+            max_bytes: None,
+            split: 0,
         }
     }
 
-    fn consume(&mut self, amt: usize) {
-        use std::io::Read;
-        let mut buf: Vec<u8> = Vec::new();
-        (0..amt).for_each(|_| buf.push(0));
-        self.read(&mut buf).unwrap();
+    /// Split the reader into its queue and backing reader.
+    ///
+    /// The [`StringReader::stats`], the limit from [`StringReader::set_max_bytes`] and where
+    /// [`std::io::Read`] stopped inside a character are dropped.
+    #[must_use]
+    pub fn into_parts(self) -> (VecDeque<String>, Option<R>) {
+        (self.queue, self.reader)
     }
-}
 
-impl StrRead for str {
-    fn peek_str(&self) -> Option<&str> {
-        Some(self)
+    /// Yield the first segment, then every `step`-th segment after it.
+    ///
+    /// The segments in between are discarded.
+    ///
+    /// # Panics
+    /// Panics if `step` is `0`, like [`Iterator::step_by`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["0", "1", "2", "3", "4"].map(String::from)).into();
+    /// let stepped: Vec<String> = sread.step_by(2).collect();
+    /// assert_eq!(stepped, ["0", "2", "4"]);
+    /// ```
+    #[must_use]
+    pub fn step_by(self, step: usize) -> StepByReader<R> {
+        assert!(step != 0, "step must not be zero");
+        StepByReader { inner: self, step }
     }
 
-    // fn peek_mut_str<'a>(&'a mut self) -> Option<&'a mut str> {
-    //     Some(self)
-    // }
-}
-impl RealStrRead for str {
-    fn pop_str(&mut self) -> Option<&str> {
-        Some(self)
-    }
-}
-impl<R: StrRead + ?Sized> StrRead for Box<R> {
-    fn peek_str(&self) -> Option<&str> {
-        (**self).peek_str()
+    /// Pair up segments from `self` and `other`, one from each per step.
+    ///
+    /// Stops as soon as either reader is empty. A segment is only popped from `self` when `other`
+    /// still has one to pair it with, so the leftovers can be recovered with
+    /// [`ZipReader::into_inner`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let letters: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+    /// let digits: StringReader = VecDeque::from(["1", "2"].map(String::from)).into();
+    /// let mut zipped = letters.zip(digits);
+    /// assert_eq!(zipped.next(), Some(("a".to_string(), "1".to_string())));
+    /// assert_eq!(zipped.next(), Some(("b".to_string(), "2".to_string())));
+    /// assert_eq!(zipped.next(), None);
+    /// let (mut letters, _) = zipped.into_inner();
+    /// assert_eq!(letters.pop_string(), Some("c".to_string()));
+    /// ```
+    #[must_use]
+    pub fn zip<S: StringRead>(self, other: StringReader<S>) -> ZipReader<R, S> {
+        ZipReader { a: self, b: other }
     }
 
-    // fn peek_mut_str<'a>(&'a mut self) -> Option<&'a mut str> {
-    //     (**self).peek_mut_str()
-    // }
-}
-impl<R: RealStrRead + ?Sized> RealStrRead for Box<R> {
-    fn pop_str(&mut self) -> Option<&str> {
-        (**self).pop_str()
+    /// Yield each segment together with its index, starting at `0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+    /// let mut e = sread.enumerate();
+    /// assert_eq!(e.next(), Some((0, "a".to_string())));
+    /// assert_eq!(e.next(), Some((1, "b".to_string())));
+    /// assert_eq!(e.next(), Some((2, "c".to_string())));
+    /// assert_eq!(e.next(), None);
+    /// ```
+    #[must_use]
+    pub fn enumerate(self) -> EnumerateReader<R> {
+        EnumerateReader {
+            inner: self,
+            count: 0,
+        }
     }
-}
-
-#[derive(Clone, Debug)]
-pub struct StrReader<'a, R: RealStrRead = Box<str>> {
-    pub queue: VecDeque<&'a str>,
-    pub reader: Option<R>,
-}
 
-impl<'a, R: RealStrRead> Default for StrReader<'a, R> {
-    fn default() -> Self {
-        Self {
-            queue: Default::default(),
-            reader: None,
+    /// Drain every segment, folding them into an accumulator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+    /// let joined = sread.fold(String::new(), |mut acc, s| {
+    ///     acc.push_str(&s);
+    ///     acc
+    /// });
+    /// assert_eq!(joined, "abc");
+    /// assert!(sread.is_empty());
+    /// ```
+    pub fn fold<B>(&mut self, init: B, mut f: impl FnMut(B, String) -> B) -> B {
+        let mut acc = init;
+        while let Some(s) = self.pop_string() {
+            acc = f(acc, s);
         }
+        acc
     }
-}
 
-impl<'a, R: RealStrRead> From<R> for StrReader<'a, R> {
-    fn from(value: R) -> Self {
-        Self {
-            queue: Default::default(),
-            reader: Some(value),
-        }
+    /// Drain every segment, reducing them into one using the first segment as the accumulator.
+    ///
+    /// Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab", "abcd", "abc"].map(String::from)).into();
+    /// let longest = sread.reduce(|a, b| if b.len() > a.len() { b } else { a });
+    /// assert_eq!(longest, Some("abcd".to_string()));
+    /// assert_eq!(sread.reduce(|a, _| a), None);
+    /// ```
+    pub fn reduce(&mut self, f: impl FnMut(String, String) -> String) -> Option<String> {
+        let first = self.pop_string()?;
+        Some(self.fold(first, f))
     }
-}
 
-impl<'a, R: RealStrRead> From<VecDeque<&'a str>> for StrReader<'a, R> {
-    fn from(value: VecDeque<&'a str>) -> Self {
-        Self {
-            queue: value,
-            reader: None,
+    /// Like [`StringReader::fold`], but stops at the first `Err`.
+    ///
+    /// The segment that caused the error is consumed, but everything after it stays in the
+    /// reader so that processing can be resumed later.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["1", "2", "x", "4"].map(String::from)).into();
+    /// let sum = sread.try_fold(0, |acc, s| s.parse::<i32>().map(|n| acc + n));
+    /// assert!(sum.is_err());
+    /// assert_eq!(sread.pop_string(), Some("4".to_string()));
+    /// assert_eq!(sread.pop_string(), None);
+    /// ```
+    pub fn try_fold<B, E>(
+        &mut self,
+        init: B,
+        mut f: impl FnMut(B, String) -> Result<B, E>,
+    ) -> Result<B, E> {
+        let mut acc = init;
+        while let Some(s) = self.pop_string() {
+            acc = f(acc, s)?;
         }
+        Ok(acc)
     }
-}
 
-impl<'a, R: RealStrRead> StrReader<'a, R> {
+    /// Call `f` with each segment as it passes through, without changing the stream.
+    ///
+    /// `f` is called once per segment when it's popped; peeking doesn't call it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::cell::RefCell;
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringRead, StringReader};
+    ///
+    /// let seen = RefCell::new(Vec::new());
+    /// let sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// let mut inspected = sread.inspect(|s| seen.borrow_mut().push(s.to_string()));
+    /// assert_eq!(inspected.peek_str(), Some("a"));
+    /// assert_eq!(inspected.pop_string(), Some("a".to_string()));
+    /// assert_eq!(inspected.pop_string(), Some("b".to_string()));
+    /// assert_eq!(inspected.pop_string(), None);
+    /// assert_eq!(*seen.borrow(), ["a", "b"]);
+    /// ```
     #[must_use]
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-
-impl<'a, R: RealStrRead> StrRead for StrReader<'a, R> {
-    fn peek_str(&self) -> Option<&str> {
-        (self.queue.front().copied()).or_else(|| self.reader.as_ref().and_then(|r| r.peek_str()))
+    pub fn inspect<F: FnMut(&str)>(self, f: F) -> InspectReader<R, F> {
+        InspectReader { inner: self, f }
     }
 
-    // fn peek_mut_str<'b>(&'b mut self) -> Option<&'b mut str> {
-    //     (self.stack.last_mut().map(|s| s.as_mut()))
-    //         .or_else(|| self.reader.as_mut().map(|r| r.peek_mut_str())?)
-    // }
-
-    fn is_empty(&self) -> bool {
-        self.queue.is_empty() && self.reader.as_ref().map_or(true, |r| r.is_empty())
+    /// Repeat the segments endlessly.
+    ///
+    /// The segments are remembered as they are popped for the first time, and once the reader
+    /// runs out they are replayed from the beginning. A cycle of an empty reader stays empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// let mut cycled = sread.cycle();
+    /// let popped: Vec<String> = cycled.by_ref().take(5).collect();
+    /// assert_eq!(popped, ["a", "b", "a", "b", "a"]);
+    /// assert!(!cycled.is_empty());
+    ///
+    /// let mut nothing = StringReader::<String>::new().cycle();
+    /// assert!(nothing.is_empty());
+    /// assert_eq!(nothing.next(), None);
+    /// ```
+    #[must_use]
+    pub fn cycle(self) -> CycleReader<R> {
+        CycleReader {
+            inner: self,
+            seen: Vec::new(),
+            pos: 0,
+        }
     }
-}
 
-impl<'a, R: RealStrRead> RealStrRead for StrReader<'a, R> {
-    fn pop_str(&mut self) -> Option<&str> {
-        self.queue
-            .pop_front()
-            .or_else(|| self.reader.as_mut().and_then(|r| r.pop_str()))
+    /// Move the queued segments from index `idx` onward into a new reader.
+    ///
+    /// The first `idx` segments stay in `self`, and the backing reader is left untouched.
+    ///
+    /// # Panics
+    /// Panics if `idx` is greater than the length of the queue.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+    /// let tail = sread.split_off(1);
+    /// assert_eq!(sread.queue, ["a"]);
+    /// assert_eq!(tail.queue, ["b", "c"]);
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// assert_eq!(sread.split_off(0).queue, ["a", "b"]);
+    /// assert!(sread.queue.is_empty());
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// assert!(sread.split_off(2).queue.is_empty());
+    /// assert_eq!(sread.queue, ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, idx: usize) -> Self {
+        self.queue.split_off(idx).into()
     }
-}
 
-impl<'r, R: RealStrRead> StrWrite<'r> for StrReader<'r, R> {
-    fn push_str(&'r mut self, s: &'r str) {
-        self.queue.push_back(s);
+    /// Move every queued segment of `other` to the back of `self`'s queue.
+    ///
+    /// `other`'s queue is left empty, and its backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// let mut other: StringReader = VecDeque::from(["c", "d"].map(String::from)).into();
+    /// sread.append(&mut other);
+    /// assert_eq!(sread.queue, ["a", "b", "c", "d"]);
+    /// assert!(other.queue.is_empty());
+    /// ```
+    pub fn append<S: StringRead>(&mut self, other: &mut StringReader<S>) {
+        self.queue.append(&mut other.queue);
     }
 
-    fn shift_str(&'r mut self, s: &'r str) {
+    /// Move every queued segment of `other` to the front of `self`'s queue.
+    ///
+    /// The moved segments keep their order and will be popped before `self`'s existing ones.
+    /// `other`'s queue is left empty, and its backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// let mut other: StringReader = VecDeque::from(["x", "y"].map(String::from)).into();
+    /// sread.prepend(&mut other);
+    /// assert_eq!(sread.queue, ["x", "y", "a", "b"]);
+    /// assert!(other.queue.is_empty());
+    /// ```
+    pub fn prepend<S: StringRead>(&mut self, other: &mut StringReader<S>) {
+        other.queue.append(&mut self.queue);
+        std::mem::swap(&mut self.queue, &mut other.queue);
+        self.split = 0;
+    }
+
+    /// Move whole segments from the front of `self` to the back of `other`'s queue, up to
+    /// `max_bytes` bytes in total, and return how many bytes were moved.
+    ///
+    /// Moving stops at the first segment that would go over the limit, so segments are never
+    /// split and the order is kept. Segments are taken from the backing reader once the queue
+    /// runs out.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab", "cd", "efg", "h"].map(String::from)).into();
+    /// let mut other: StringReader = VecDeque::from(["z".to_string()]).into();
+    /// assert_eq!(sread.pour_into(&mut other, 6), 4);
+    /// assert_eq!(sread.queue, ["efg", "h"]);
+    /// assert_eq!(other.queue, ["z", "ab", "cd"]);
+    /// assert_eq!(sread.pour_into(&mut other, 2), 0);
+    /// assert_eq!(sread.queue, ["efg", "h"]);
+    /// ```
+    pub fn pour_into<S: StringRead>(
+        &mut self,
+        other: &mut StringReader<S>,
+        max_bytes: usize,
+    ) -> usize {
+        let mut moved = 0;
+        while self
+            .peek_str()
+            .is_some_and(|s| moved + s.len() <= max_bytes)
+        {
+            let s = self.take_next().unwrap();
+            moved += s.len();
+            other.queue.push_back(s);
+        }
+        self.consumed(Consumed::Strings, moved);
+        moved
+    }
+
+    /// Replace each queued segment with its [`str::escape_default`] form.
+    ///
+    /// Only the queue is transformed; the backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a\nb", "é"].map(String::from)).into();
+    /// sread.escape_default();
+    /// assert_eq!(sread.queue, ["a\\nb", "\\u{e9}"]);
+    /// ```
+    pub fn escape_default(&mut self) {
+        (self.queue.iter_mut()).for_each(|s| *s = s.escape_default().to_string());
+    }
+
+    /// Replace each queued segment with its [`str::escape_debug`] form.
+    ///
+    /// Only the queue is transformed; the backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a\nb", "é", "\u{301}"].map(String::from)).into();
+    /// sread.escape_debug();
+    /// assert_eq!(sread.queue, ["a\\nb", "é", "\\u{301}"]);
+    /// ```
+    pub fn escape_debug(&mut self) {
+        (self.queue.iter_mut()).for_each(|s| *s = s.escape_debug().to_string());
+    }
+
+    /// Replace the queue with `n` consecutive copies of itself.
+    ///
+    /// `n == 0` empties the queue. The backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    ///
+    /// let mut none = sread.clone();
+    /// none.repeat(0);
+    /// assert!(none.queue.is_empty());
+    ///
+    /// let mut once = sread.clone();
+    /// once.repeat(1);
+    /// assert_eq!(once.queue, ["a", "b"]);
+    ///
+    /// let mut thrice = sread.clone();
+    /// thrice.repeat(3);
+    /// assert_eq!(thrice.queue, ["a", "b", "a", "b", "a", "b"]);
+    /// ```
+    pub fn repeat(&mut self, n: usize) {
+        if n == 0 {
+            self.queue.clear();
+            return;
+        }
+        let len = self.queue.len();
+        self.queue.reserve(len * (n - 1));
+        for _ in 1..n {
+            for i in 0..len {
+                let s = self.queue[i].clone();
+                self.queue.push_back(s);
+            }
+        }
+    }
+
+    /// Get every overlapping window of `n` consecutive queued segments.
+    ///
+    /// This doesn't consume anything, and only looks at the queue.
+    ///
+    /// # Panics
+    /// Panics if `n` is `0`, like [`slice::windows`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b", "c", "d"].map(String::from)).into();
+    /// assert_eq!(sread.windows(2), [["a", "b"], ["b", "c"], ["c", "d"]]);
+    /// assert!(sread.windows(5).is_empty());
+    /// ```
+    #[must_use]
+    pub fn windows(&self, n: usize) -> Vec<Vec<&str>> {
+        assert!(n != 0, "window size must not be zero");
+        let segments: Vec<&str> = self.queue.iter().map(|s| s.as_str()).collect();
+        segments.windows(n).map(|w| w.to_vec()).collect()
+    }
+
+    /// Drain every segment into non-overlapping chunks of up to `n` segments each.
+    ///
+    /// The last chunk may be shorter than `n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is `0`, like [`slice::chunks`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b", "c", "d", "e"].map(String::from)).into();
+    /// let chunks = sread.chunks(2);
+    /// assert_eq!(chunks, vec![vec!["a", "b"], vec!["c", "d"], vec!["e"]]);
+    /// assert!(sread.is_empty());
+    /// ```
+    pub fn chunks(&mut self, n: usize) -> Vec<Vec<String>> {
+        assert!(n != 0, "chunk size must not be zero");
+        let mut chunks = Vec::new();
+        let mut chunk = Vec::with_capacity(n);
+        while let Some(s) = self.pop_string() {
+            chunk.push(s);
+            if chunk.len() == n {
+                chunks.push(std::mem::replace(&mut chunk, Vec::with_capacity(n)));
+            }
+        }
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    /// Sort the queued segments lexicographically.
+    ///
+    /// Only the queue is sorted; the backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["b", "c", "a"].map(String::from)).into();
+    /// sread.sort();
+    /// assert_eq!(sread.pop_string(), Some("a".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("b".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("c".to_string()));
+    /// ```
+    pub fn sort(&mut self) {
+        self.queue.make_contiguous().sort();
+    }
+
+    /// Sort the queued segments with a comparator function.
+    ///
+    /// Only the queue is sorted; the backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ccc", "a", "bb"].map(String::from)).into();
+    /// sread.sort_by(|a, b| a.len().cmp(&b.len()));
+    /// assert_eq!(sread.pop_string(), Some("a".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("bb".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("ccc".to_string()));
+    /// ```
+    pub fn sort_by(&mut self, cmp: impl FnMut(&String, &String) -> std::cmp::Ordering) {
+        self.queue.make_contiguous().sort_by(cmp);
+    }
+
+    /// Check if the queued segments are in non-decreasing lexicographic order.
+    ///
+    /// Only the queue is checked; the backing reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sorted: StringReader = VecDeque::from(["a", "b", "b"].map(String::from)).into();
+    /// assert!(sorted.is_sorted());
+    /// let unsorted: StringReader = VecDeque::from(["b", "a"].map(String::from)).into();
+    /// assert!(!unsorted.is_sorted());
+    /// let single: StringReader = VecDeque::from(["z".to_string()]).into();
+    /// assert!(single.is_sorted());
+    /// ```
+    #[must_use]
+    pub fn is_sorted(&self) -> bool {
+        self.queue.iter().is_sorted()
+    }
+
+    /// Check if the queued segments are sorted using a comparator function.
+    ///
+    /// `compare` should return `true` if its two arguments are in order, like
+    /// [`slice::is_sorted_by`]. Only the queue is checked; the backing reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["c", "bb", "aaa"].map(String::from)).into();
+    /// assert!(sread.is_sorted_by(|a, b| a.len() <= b.len()));
+    /// assert!(!sread.is_sorted_by(|a, b| a <= b));
+    /// ```
+    #[must_use]
+    pub fn is_sorted_by(&self, mut compare: impl FnMut(&String, &String) -> bool) -> bool {
+        self.queue.iter().is_sorted_by(|a, b| compare(a, b))
+    }
+
+    /// Create a reader with the bytes as its only segment, if they are valid UTF-8.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from_bytes(b"hai".to_vec()).unwrap();
+    /// assert_eq!(sread.pop_string(), Some("hai".to_string()));
+    /// assert!(StringReader::<String>::from_bytes(vec![0xff, 0xfe]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, std::string::FromUtf8Error> {
+        Ok(VecDeque::from([String::from_utf8(bytes)?]).into())
+    }
+
+    /// Create a reader with the bytes as its only segment, replacing invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from_bytes_lossy(b"hai\xffbai".to_vec());
+    /// assert_eq!(sread.pop_string(), Some("hai\u{fffd}bai".to_string()));
+    /// ```
+    #[must_use]
+    pub fn from_bytes_lossy(bytes: Vec<u8>) -> Self {
+        let s = String::from_utf8(bytes)
+            .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+        VecDeque::from([s]).into()
+    }
+
+    /// Read everything from `r` into a reader with a single segment.
+    ///
+    /// # Errors
+    /// Returns an error if reading fails or if the data is not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use string_reader::{StrRead, StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from_read(Cursor::new("hai\nbai")).unwrap();
+    /// assert_eq!(sread.pop_string(), Some("hai\nbai".to_string()));
+    /// assert!(sread.is_empty());
+    /// ```
+    pub fn from_read(mut r: impl std::io::Read) -> std::io::Result<Self> {
+        let mut s = String::new();
+        r.read_to_string(&mut s)?;
+        Ok(VecDeque::from([s]).into())
+    }
+
+    /// Count the UTF-16 code units needed to encode the queued segments.
+    ///
+    /// Only the queue is counted; the backing reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a😀", "é"].map(String::from)).into();
+    /// assert_eq!(sread.utf16_len(), 4);
+    /// assert_eq!(sread.queue.iter().map(|s| s.chars().count()).sum::<usize>(), 3);
+    /// ```
+    #[must_use]
+    pub fn utf16_len(&self) -> usize {
+        self.queue.iter().map(|s| s.encode_utf16().count()).sum()
+    }
+
+    /// Iterate over the bytes of the queued segments, without consuming anything.
+    ///
+    /// Only the queue is iterated; the backing reader is ignored.
+    ///
+    /// Note that with [`std::io::Read`] in scope, `sread.bytes()` resolves to the consuming
+    /// [`std::io::Read::bytes`] instead. Use `StringReader::bytes(&sread)` in that case.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["ab", "€"].map(String::from)).into();
+    /// assert_eq!(sread.bytes().collect::<Vec<u8>>(), "ab€".as_bytes());
+    /// ```
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.queue.iter().flat_map(|s| s.bytes())
+    }
+
+    /// Iterate over the characters of the queued segments, without consuming anything.
+    ///
+    /// Only the queue is iterated; the backing reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["ab", "€c"].map(String::from)).into();
+    /// assert_eq!(sread.chars().collect::<Vec<char>>(), ['a', 'b', '€', 'c']);
+    /// assert_eq!(sread.queue, ["ab", "€c"]);
+    /// ```
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.queue.iter().flat_map(|s| s.chars())
+    }
+
+    /// Keep only the first `len` queued segments, dropping the rest.
+    ///
+    /// Does nothing if the queue is already shorter. The backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+    /// sread.truncate(5);
+    /// assert_eq!(sread.queue, ["a", "b", "c"]);
+    /// sread.truncate(1);
+    /// assert_eq!(sread.queue, ["a"]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.queue.truncate(len);
+    }
+
+    /// Resize the queue to `new_len` segments, filling new ones by calling `f`.
+    ///
+    /// If the queue is longer than `new_len`, it's truncated instead. The backing reader is left
+    /// untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a".to_string()]).into();
+    /// let mut n = 0;
+    /// sread.resize_with(3, || {
+    ///     n += 1;
+    ///     n.to_string()
+    /// });
+    /// assert_eq!(sread.queue, ["a", "1", "2"]);
+    /// sread.resize_with(1, String::new);
+    /// assert_eq!(sread.queue, ["a"]);
+    /// ```
+    pub fn resize_with(&mut self, new_len: usize, f: impl FnMut() -> String) {
+        self.queue.resize_with(new_len, f);
+    }
+
+    /// Split the queued content at the last occurrence of `delim`, like [`str::rsplit_once`].
+    ///
+    /// Everything after the delimiter is moved into the returned reader, everything before it
+    /// stays in `self`, and the delimiter itself is dropped. If `delim` is not found, `self` is
+    /// left untouched and the returned reader is empty. Only the queue is searched; the backing
+    /// reader stays with `self`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a.b", ".c.d", "e"].map(String::from)).into();
+    /// let tail = sread.rsplit_off('.');
+    /// assert_eq!(sread.queue, ["a.b", ".c"]);
+    /// assert_eq!(tail.queue, ["d", "e"]);
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["abc".to_string()]).into();
+    /// assert!(sread.rsplit_off('.').queue.is_empty());
+    /// assert_eq!(sread.queue, ["abc"]);
+    /// ```
+    #[must_use]
+    pub fn rsplit_off(&mut self, delim: char) -> Self {
+        let Some((i, pos)) = (self.queue.iter().enumerate().rev())
+            .find_map(|(i, s)| s.rfind(delim).map(|pos| (i, pos)))
+        else {
+            return Self::new();
+        };
+        let mut tail = self.queue.split_off(i + 1);
+        let s = self.queue.pop_back().unwrap();
+        let after = &s[pos + delim.len_utf8()..];
+        if !after.is_empty() {
+            tail.push_front(after.to_string());
+        }
+        if pos != 0 {
+            self.queue.push_back(s[..pos].to_string());
+        }
+        tail.into()
+    }
+
+    /// Drain every segment and concatenate them into one `String`.
+    ///
+    /// The result is allocated once with the exact length of the content.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = (0..100).map(|n| n.to_string()).collect::<VecDeque<_>>().into();
+    /// let expected: String = (0..100).map(|n| n.to_string()).collect();
+    /// let s = sread.concat();
+    /// assert_eq!(s, expected);
+    /// assert_eq!(s.capacity(), s.len());
+    /// assert!(sread.is_empty());
+    /// ```
+    #[must_use]
+    pub fn concat(&mut self) -> String {
+        let s = self.take_all();
+        self.consumed(Consumed::Strings, s.len());
+        s
+    }
+
+    /// Remove everything and concatenate it, without counting it in [`StringReader::stats`].
+    fn take_all(&mut self) -> String {
+        let segments: Vec<String> = std::iter::from_fn(|| self.take_next()).collect();
+        segments.concat()
+    }
+
+    /// Move the next segment of the backing reader to the back of the queue.
+    ///
+    /// Returns `false` if the backing reader had nothing left.
+    fn pull(&mut self) -> bool {
+        match self.reader.as_mut().and_then(|r| r.pop_string()) {
+            Some(s) => {
+                self.queue.push_back(s);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consume the remaining stream if it's exactly one character.
+    ///
+    /// Otherwise, returns `None` and leaves the stream untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from("x".to_string());
+    /// assert_eq!(sread.try_into_char(), Some('x'));
+    /// assert!(sread.is_empty());
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["x", "y"].map(String::from)).into();
+    /// assert_eq!(sread.try_into_char(), None);
+    /// assert_eq!(sread.queue, ["x", "y"]);
+    ///
+    /// assert_eq!(StringReader::<String>::new().try_into_char(), None);
+    /// ```
+    pub fn try_into_char(&mut self) -> Option<char> {
+        while self.chars().nth(1).is_none() && self.pull() {}
+        let mut chars = self.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return None;
+        };
+        drop(chars);
+        self.queue.clear();
+        self.consumed(Consumed::Chars, c.len_utf8());
+        Some(c)
+    }
+
+    /// Count the non-overlapping occurrences of `pat` in the queued content.
+    ///
+    /// The queue is concatenated first, so matches spanning segment boundaries are counted too.
+    /// The backing reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["abab", "a", "bx"].map(String::from)).into();
+    /// assert_eq!(sread.match_count("ab"), 3);
+    /// assert_eq!(sread.match_count("x"), 1);
+    /// assert_eq!(sread.match_count("y"), 0);
+    /// ```
+    #[must_use]
+    pub fn match_count(&self, pat: &str) -> usize {
+        let content: String = self.queue.iter().map(String::as_str).collect();
+        content.matches(pat).count()
+    }
+
+    /// Create a reader from `input` split on `delim`, using [`str::split_terminator`] semantics.
+    ///
+    /// Unlike [`str::split`], a trailing delimiter doesn't produce an empty last segment.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = StringReader::from_split_terminator("a,b,", ',');
+    /// assert_eq!(sread.queue, ["a", "b"]);
+    /// assert_eq!("a,b,".split(',').collect::<Vec<_>>(), ["a", "b", ""]);
+    /// ```
+    #[must_use]
+    pub fn from_split_terminator(input: &str, delim: char) -> Self {
+        input
+            .split_terminator(delim)
+            .map(String::from)
+            .collect::<VecDeque<_>>()
+            .into()
+    }
+
+    /// Create a reader from `input` split on `delim`, using [`str::split_inclusive`] semantics.
+    ///
+    /// Each segment keeps its trailing delimiter, so concatenating them gives back `input`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from_split_inclusive("a\nb\nc", '\n');
+    /// assert_eq!(sread.queue, ["a\n", "b\n", "c"]);
+    /// assert_eq!(sread.concat(), "a\nb\nc");
+    /// ```
+    #[must_use]
+    pub fn from_split_inclusive(input: &str, delim: char) -> Self {
+        input
+            .split_inclusive(delim)
+            .map(String::from)
+            .collect::<VecDeque<_>>()
+            .into()
+    }
+
+    /// Pop up to `n` whole segments at once.
+    ///
+    /// Returns fewer than `n` segments if the reader runs out.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b", "c", "d"].map(String::from)).into();
+    /// assert_eq!(sread.pop_n(1), ["a"]);
+    /// assert_eq!(sread.pop_n(2), ["b", "c"]);
+    /// assert_eq!(sread.pop_n(5), ["d"]);
+    /// assert!(sread.pop_n(1).is_empty());
+    /// ```
+    pub fn pop_n(&mut self, n: usize) -> Vec<String> {
+        (0..n).map_while(|_| self.pop_string()).collect()
+    }
+
+    /// Get the total number of bytes consumed through [`std::io::Read`] and
+    /// [`std::io::BufRead`].
+    ///
+    /// This is the same as the `read` field of [`StringReader::stats`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use std::io::Read;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["hello", "world"].map(String::from)).into();
+    /// let mut buf = [0; 3];
+    /// sread.read(&mut buf).unwrap();
+    /// assert_eq!(sread.stream_position_bytes(), 3);
+    /// sread.read(&mut buf).unwrap();
+    /// assert_eq!(sread.stream_position_bytes(), 6);
+    /// let mut rest = String::new();
+    /// sread.read_to_string(&mut rest).unwrap();
+    /// assert_eq!(rest, "orld");
+    /// assert_eq!(sread.stream_position_bytes(), 10);
+    /// ```
+    #[must_use]
+    pub fn stream_position_bytes(&self) -> u64 {
+        self.stats.read
+    }
+
+    /// Get a short, human-readable view of the reader for logging.
+    ///
+    /// The queued segments are listed, followed by ` + <reader present>` if there is a backing
+    /// reader.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("rest".to_string());
+    /// sread.queue = VecDeque::from(["a", "b"].map(String::from));
+    /// assert_eq!(sread.debug_segments(), r#"["a", "b"] + <reader present>"#);
+    /// sread.reader = None;
+    /// assert_eq!(sread.debug_segments(), r#"["a", "b"]"#);
+    /// ```
+    #[must_use]
+    pub fn debug_segments(&self) -> String {
+        match self.reader {
+            Some(_) => format!("{:?} + <reader present>", self.queue),
+            None => format!("{:?}", self.queue),
+        }
+    }
+
+    /// Get the next character without consuming it.
+    ///
+    /// Empty queued segments are skipped. If the queue has no characters left, the first
+    /// character of the backing reader's next `&str` is returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["", "€c"].map(String::from)).into();
+    /// assert_eq!(sread.peek_char(), Some('€'));
+    /// assert_eq!(StringReader::<String>::new().peek_char(), None);
+    /// ```
+    #[must_use]
+    pub fn peek_char(&self) -> Option<char> {
+        (self.chars().next()).or_else(|| self.reader.as_ref()?.peek_str()?.chars().next())
+    }
+
+    /// Check if the next character is an ASCII digit.
+    ///
+    /// Returns `false` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = StringReader::from("1a".to_string());
+    /// assert!(sread.next_is_digit());
+    /// assert!(!StringReader::<String>::from("a1".to_string()).next_is_digit());
+    /// assert!(!StringReader::<String>::new().next_is_digit());
+    /// ```
+    #[must_use]
+    pub fn next_is_digit(&self) -> bool {
+        self.peek_char().is_some_and(|c| c.is_ascii_digit())
+    }
+
+    /// Check if the next character is alphabetic.
+    ///
+    /// Returns `false` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = StringReader::from("é1".to_string());
+    /// assert!(sread.next_is_alphabetic());
+    /// assert!(!StringReader::<String>::from("1a".to_string()).next_is_alphabetic());
+    /// assert!(!StringReader::<String>::new().next_is_alphabetic());
+    /// ```
+    #[must_use]
+    pub fn next_is_alphabetic(&self) -> bool {
+        self.peek_char().is_some_and(char::is_alphabetic)
+    }
+
+    /// Check if the next character is whitespace.
+    ///
+    /// Returns `false` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = StringReader::from("\tx".to_string());
+    /// assert!(sread.next_is_whitespace());
+    /// assert!(!StringReader::<String>::from("x ".to_string()).next_is_whitespace());
+    /// assert!(!StringReader::<String>::new().next_is_whitespace());
+    /// ```
+    #[must_use]
+    pub fn next_is_whitespace(&self) -> bool {
+        self.peek_char().is_some_and(char::is_whitespace)
+    }
+
+    /// Consume and return the run of characters matching `pred`, across segments.
+    ///
+    /// The first character that doesn't match is left in the stream.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["12", "34+5"].map(String::from)).into();
+    /// assert_eq!(sread.consume_while_char(|c| c.is_ascii_digit()), "1234");
+    /// assert_eq!(sread.pop_string(), Some("+5".to_string()));
+    /// ```
+    pub fn consume_while_char(&mut self, mut pred: impl FnMut(char) -> bool) -> String {
+        let mut out = String::new();
+        while let Some(s) = self.peek_mut_string() {
+            let end = s.char_indices().find(|&(_, c)| !pred(c)).map(|(i, _)| i);
+            if let Some(i) = end {
+                out.extend(s.drain(..i));
+                break;
+            }
+            out.push_str(&self.take_next().unwrap());
+        }
+        self.consumed(Consumed::Strings, out.len());
+        out
+    }
+
+    /// Get the run of characters matching `pred`, across segments, without consuming it.
+    ///
+    /// Content is moved from the backing reader into the queue as needed, but the stream itself
+    /// is unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("34+5".to_string());
+    /// sread.queue = VecDeque::from(["12".to_string()]);
+    /// assert_eq!(sread.peek_while_char(|c| c.is_ascii_digit()), "1234");
+    /// assert_eq!(sread.queue, ["12", "34+5"]);
+    /// assert_eq!(sread.peek_while_char(|c| c.is_alphabetic()), "");
+    /// assert_eq!(sread.consume_while_char(|c| c.is_ascii_digit()), "1234");
+    /// ```
+    pub fn peek_while_char(&mut self, mut pred: impl FnMut(char) -> bool) -> String {
+        let mut out = String::new();
+        let mut idx = 0;
+        while idx < self.queue.len() || self.pull() {
+            let s = &self.queue[idx];
+            if let Some((i, _)) = s.char_indices().find(|&(_, c)| !pred(c)) {
+                out.push_str(&s[..i]);
+                break;
+            }
+            out.push_str(s);
+            idx += 1;
+        }
+        out
+    }
+
+    /// Consume leading whitespace across segments, returning how many characters were skipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from([" \t", "\n", "  x "].map(String::from)).into();
+    /// assert_eq!(sread.consume_whitespace(), 5);
+    /// assert_eq!(sread.pop_string(), Some("x ".to_string()));
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["x ".to_string()]).into();
+    /// assert_eq!(sread.consume_whitespace(), 0);
+    /// assert_eq!(sread.queue, ["x "]);
+    /// ```
+    pub fn consume_whitespace(&mut self) -> usize {
+        self.consume_while_char(char::is_whitespace).chars().count()
+    }
+
+    /// Get a [`StrReader`] borrowing the queued segments.
+    ///
+    /// The `StringReader` can't be changed while the `StrReader` is alive. Only the queue is
+    /// borrowed; the backing reader is not included.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{RealStrRead, StringReader};
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// let mut borrowed = sread.flatten_into_str_reader();
+    /// assert_eq!(borrowed.pop_str(), Some("a"));
+    /// assert_eq!(borrowed.pop_str(), Some("b"));
+    /// assert_eq!(borrowed.pop_str(), None);
+    /// assert_eq!(sread.queue, ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn flatten_into_str_reader(&self) -> StrReader<'_> {
+        self.queue
+            .iter()
+            .map(String::as_str)
+            .collect::<VecDeque<_>>()
+            .into()
+    }
+
+    /// Get the length in bytes of the next character when encoded as UTF-8.
+    ///
+    /// Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let len = |s: &str| StringReader::<String>::from(s.to_string()).next_char_len();
+    /// assert_eq!(len("a"), Some(1));
+    /// assert_eq!(len("é"), Some(2));
+    /// assert_eq!(len("€"), Some(3));
+    /// assert_eq!(len("😀"), Some(4));
+    /// assert_eq!(len(""), None);
+    /// ```
+    #[must_use]
+    pub fn next_char_len(&self) -> Option<usize> {
+        self.peek_char().map(char::len_utf8)
+    }
+
+    /// Consume whole characters from the front, up to `max_bytes` bytes in total.
+    ///
+    /// A character that would go over the budget is left in the stream, so the result is never
+    /// cut in the middle of a character.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab", "€c"].map(String::from)).into();
+    /// assert_eq!(sread.pop_bytes(4), "ab");
+    /// assert_eq!(sread.pop_bytes(4), "€c");
+    /// assert_eq!(sread.pop_string(), None);
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab", "c"].map(String::from)).into();
+    /// assert_eq!(sread.pop_bytes(10), "abc");
+    /// ```
+    pub fn pop_bytes(&mut self, max_bytes: usize) -> String {
+        let mut out = String::new();
+        while let Some(s) = self.peek_mut_string() {
+            let budget = max_bytes - out.len();
+            if s.len() > budget {
+                let end = (0..=budget).rev().find(|&i| s.is_char_boundary(i)).unwrap();
+                out.extend(s.drain(..end));
+                break;
+            }
+            out.push_str(&self.take_next().unwrap());
+        }
+        self.consumed(Consumed::Strings, out.len());
+        out
+    }
+
+    /// Turn the reader into an iterator popping every segment.
+    ///
+    /// This is what `for s in sread` uses.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+    /// let mut strings = sread.into_strings();
+    /// assert_eq!(strings.size_hint(), (3, Some(3)));
+    /// strings.next();
+    /// assert_eq!(strings.size_hint(), (2, Some(2)));
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// let mut popped = Vec::new();
+    /// for s in sread {
+    ///     popped.push(s);
+    /// }
+    /// assert_eq!(popped, ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn into_strings(self) -> IntoStrings<R> {
+        IntoStrings { inner: self }
+    }
+
+    /// Pop the next segment, or return `default` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("hai".to_string());
+    /// assert_eq!(sread.pop_string_or("bai".to_string()), "hai");
+    /// assert_eq!(sread.pop_string_or("bai".to_string()), "bai");
+    /// ```
+    pub fn pop_string_or(&mut self, default: String) -> String {
+        self.pop_string().unwrap_or(default)
+    }
+
+    /// Pop the next segment, or call `f` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("hai".to_string());
+    /// assert_eq!(sread.pop_string_or_else(|| "bai".to_string()), "hai");
+    /// assert_eq!(sread.pop_string_or_else(|| "bai".to_string()), "bai");
+    /// ```
+    pub fn pop_string_or_else(&mut self, f: impl FnOnce() -> String) -> String {
+        self.pop_string().unwrap_or_else(f)
+    }
+
+    /// Replace every run of whitespace-only queued segments with a single `" "` segment.
+    ///
+    /// Empty segments are not considered whitespace. The backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader =
+    ///     VecDeque::from(["a", " ", "\t", "\n", "b", "  ", "c "].map(String::from)).into();
+    /// sread.collapse_whitespace();
+    /// assert_eq!(sread.queue, ["a", " ", "b", " ", "c "]);
+    /// ```
+    pub fn collapse_whitespace(&mut self) {
+        let mut out = VecDeque::with_capacity(self.queue.len());
+        let mut in_run = false;
+        for s in self.queue.drain(..) {
+            let is_ws = !s.is_empty() && s.chars().all(char::is_whitespace);
+            if !is_ws {
+                out.push_back(s);
+            } else if !in_run {
+                out.push_back(" ".to_string());
+            }
+            in_run = is_ws;
+        }
+        self.queue = out;
+    }
+
+    /// Get the whole stream as a `&str` if it's a single queued segment.
+    ///
+    /// Returns `None` if there are several segments, none at all, or a backing reader.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let single: StringReader = VecDeque::from(["hai".to_string()]).into();
+    /// assert_eq!(single.as_single_str(), Some("hai"));
+    /// let multi: StringReader = VecDeque::from(["h", "ai"].map(String::from)).into();
+    /// assert_eq!(multi.as_single_str(), None);
+    /// assert_eq!(StringReader::<String>::new().as_single_str(), None);
+    /// ```
+    #[must_use]
+    pub fn as_single_str(&self) -> Option<&str> {
+        match (self.queue.len(), &self.reader) {
+            (1, None) => self.queue.front().map(String::as_str),
+            _ => None,
+        }
+    }
+
+    /// Make the whole stream a single queued segment and get it as a `&str`.
+    ///
+    /// If there are several segments or the backing reader has content, everything is
+    /// concatenated first, like [`VecDeque::make_contiguous`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("d".to_string());
+    /// sread.queue = VecDeque::from(["a", "bc"].map(String::from));
+    /// assert_eq!(sread.ensure_contiguous(), "abcd");
+    /// assert_eq!(sread.queue, ["abcd"]);
+    /// assert_eq!(sread.stats().strings, 0);
+    /// assert_eq!(StringReader::<String>::new().ensure_contiguous(), "");
+    /// ```
+    pub fn ensure_contiguous(&mut self) -> &str {
+        if self.queue.len() > 1 || self.reader.as_ref().is_some_and(|r| !r.is_empty()) {
+            let s = self.take_all();
+            self.queue.push_back(s);
+        }
+        self.queue.front().map_or("", String::as_str)
+    }
+
+    /// Split the whole stream at the first occurrence of `delim`, like [`str::split_once`].
+    ///
+    /// On success, everything is consumed: the part before `delim` is returned as a `String`,
+    /// and the part after it as a new reader. Returns `None` if `delim` is not found, after
+    /// moving the backing reader's content into the queue.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["key=val", "ue"].map(String::from)).into();
+    /// let (key, mut value) = sread.split_once("=").unwrap();
+    /// assert_eq!(key, "key");
+    /// assert_eq!(value.concat(), "value");
+    /// assert!(sread.is_empty());
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["key:", ":value"].map(String::from)).into();
+    /// let (key, mut value) = sread.split_once("::").unwrap();
+    /// assert_eq!(key, "key");
+    /// assert_eq!(value.concat(), "value");
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["key", "value"].map(String::from)).into();
+    /// assert_eq!(sread.split_once("="), None);
+    /// assert_eq!(sread.queue, ["key", "value"]);
+    /// ```
+    pub fn split_once(&mut self, delim: &str) -> Option<(String, Self)> {
+        while self.pull() {}
+        let content: String = self.queue.iter().map(String::as_str).collect();
+        let (head, tail) = content.split_once(delim)?;
+        let tail = VecDeque::from([tail.to_string()]).into();
+        self.queue.clear();
+        self.consumed(Consumed::Strings, content.len());
+        Some((head.to_string(), tail))
+    }
+
+    /// Parse the whole stream as `T`, consuming it on success.
+    ///
+    /// The stream is made contiguous first (see [`StringReader::ensure_contiguous`]), and stays
+    /// that way if parsing fails.
+    ///
+    /// # Errors
+    /// Returns the error of [`str::parse`] if the content can't be parsed as `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["4", "2"].map(String::from)).into();
+    /// assert_eq!(sread.parse::<i32>(), Ok(42));
+    /// assert!(sread.is_empty());
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["4", "x"].map(String::from)).into();
+    /// assert!(sread.parse::<i32>().is_err());
+    /// assert_eq!(sread.queue, ["4x"]);
+    /// assert_eq!(sread.stats().strings, 0);
+    /// sread.queue = VecDeque::from(["4", "2"].map(String::from));
+    /// assert_eq!(sread.parse::<i32>(), Ok(42));
+    /// assert_eq!(sread.stats().strings, 2);
+    /// ```
+    pub fn parse<T: std::str::FromStr>(&mut self) -> Result<T, T::Err> {
+        let value = self.ensure_contiguous().parse()?;
+        let len = self.queue.iter().map(String::len).sum();
+        self.queue.clear();
+        self.consumed(Consumed::Strings, len);
+        Ok(value)
+    }
+
+    /// Pop the next segment and parse it as `T`.
+    ///
+    /// The segment is consumed even if parsing fails. Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["42", "x"].map(String::from)).into();
+    /// assert_eq!(sread.pop_parse::<i32>(), Some(Ok(42)));
+    /// assert!(matches!(sread.pop_parse::<i32>(), Some(Err(_))));
+    /// assert_eq!(sread.pop_parse::<i32>(), None);
+    /// ```
+    pub fn pop_parse<T: std::str::FromStr>(&mut self) -> Option<Result<T, T::Err>> {
+        self.pop_string().map(|s| s.parse())
+    }
+
+    /// Parse the next segment as `T`, only popping it if parsing succeeds.
+    ///
+    /// Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["42", "x"].map(String::from)).into();
+    /// assert_eq!(sread.try_pop_parse::<i32>(), Some(Ok(42)));
+    /// assert!(matches!(sread.try_pop_parse::<i32>(), Some(Err(_))));
+    /// assert_eq!(sread.pop_string(), Some("x".to_string()));
+    /// assert_eq!(sread.try_pop_parse::<i32>(), None);
+    /// ```
+    pub fn try_pop_parse<T: std::str::FromStr>(&mut self) -> Option<Result<T, T::Err>> {
+        let value = self.peek_str()?.parse();
+        if value.is_ok() {
+            self.pop_string();
+        }
+        Some(value)
+    }
+
+    /// Get a reference to the queued segments.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::new();
+    /// sread.queue_mut().push_back("hai".to_string());
+    /// assert_eq!(sread.queue(), &["hai"]);
+    /// ```
+    #[must_use]
+    pub fn queue(&self) -> &VecDeque<String> {
+        &self.queue
+    }
+
+    /// Get a mutable reference to the queued segments.
+    pub fn queue_mut(&mut self) -> &mut VecDeque<String> {
+        &mut self.queue
+    }
+
+    /// Get a reference to the backing reader, if there is one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from("hai".to_string());
+    /// sread.reader_mut().unwrap().push('!');
+    /// assert_eq!(sread.reader_ref().map(String::as_str), Some("hai!"));
+    /// assert_eq!(sread.pop_string(), Some("hai!".to_string()));
+    /// assert_eq!(StringReader::<String>::new().reader_ref(), None);
+    /// ```
+    #[must_use]
+    pub fn reader_ref(&self) -> Option<&R> {
+        self.reader.as_ref()
+    }
+
+    /// Get a mutable reference to the backing reader, if there is one.
+    pub fn reader_mut(&mut self) -> Option<&mut R> {
+        self.reader.as_mut()
+    }
+
+    /// Count how many times each distinct segment appears in the queue.
+    ///
+    /// Only the queue is counted; the backing reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::{HashMap, VecDeque};
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b", "a"].map(String::from)).into();
+    /// let expected = HashMap::from([("a".to_string(), 2), ("b".to_string(), 1)]);
+    /// assert_eq!(sread.frequencies(), expected);
+    /// ```
+    #[must_use]
+    pub fn frequencies(&self) -> std::collections::HashMap<String, usize> {
+        let mut counts = std::collections::HashMap::new();
+        for s in &self.queue {
+            *counts.entry(s.clone()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Pop every segment and call `f` on each, leaving the reader empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from("c".to_string());
+    /// sread.queue = VecDeque::from(["a", "b"].map(String::from));
+    /// let mut popped = Vec::new();
+    /// sread.for_each(|s| popped.push(s));
+    /// assert_eq!(popped, ["a", "b", "c"]);
+    /// assert!(sread.is_empty());
+    /// ```
+    pub fn for_each(&mut self, mut f: impl FnMut(String)) {
+        while let Some(s) = self.pop_string() {
+            f(s);
+        }
+    }
+
+    /// Consume exactly `n` bytes from the front and return them, across segments.
+    ///
+    /// Returns `None` and leaves the stream untouched if `n` falls inside a character or goes
+    /// past the end of the stream.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab", "€c"].map(String::from)).into();
+    /// assert_eq!(sread.split_at_byte(3), None);
+    /// assert_eq!(sread.queue, ["ab", "€c"]);
+    /// assert_eq!(sread.split_at_byte(5), Some("ab€".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("c".to_string()));
+    /// assert_eq!(sread.split_at_byte(1), None);
+    /// ```
+    pub fn split_at_byte(&mut self, n: usize) -> Option<String> {
+        while self.queue.iter().map(String::len).sum::<usize>() < n && self.pull() {}
+        let mut rem = n;
+        let mut idx = 0;
+        while idx < self.queue.len() && rem > self.queue[idx].len() {
+            rem -= self.queue[idx].len();
+            idx += 1;
+        }
+        match self.queue.get(idx) {
+            Some(s) if s.is_char_boundary(rem) => {}
+            None if rem == 0 => {}
+            _ => return None,
+        }
+        let mut out = String::with_capacity(n);
+        self.queue.drain(..idx).for_each(|s| out.push_str(&s));
+        if let Some(front) = self.queue.front_mut() {
+            out.extend(front.drain(..rem));
+            if front.is_empty() {
+                self.queue.pop_front();
+            }
+        }
+        self.consumed(Consumed::Strings, n);
+        Some(out)
+    }
+
+    /// Limit the total bytes that can be queued through [`StringReader::try_push_string`].
+    ///
+    /// `None` removes the limit. Segments that are already queued are kept even if they go over
+    /// the new limit. Note that [`StringWrite::push_string`] ignores the limit.
+    pub fn set_max_bytes(&mut self, max: Option<usize>) {
+        self.max_bytes = max;
+    }
+
+    /// Push `s` to the back of the queue, unless it would go over the limit set with
+    /// [`StringReader::set_max_bytes`].
+    ///
+    /// The queued bytes are counted on every push while a limit is set, so everything in the
+    /// queue counts, however it got there. This costs O(number of queued segments).
+    ///
+    /// # Errors
+    /// Returns `s` back if the queue can't take it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader, StringWrite};
+    ///
+    /// let mut sread: StringReader = StringReader::new();
+    /// sread.set_max_bytes(Some(5));
+    /// assert_eq!(sread.try_push_string("hai".to_string()), Ok(()));
+    /// assert_eq!(sread.try_push_string("bai".to_string()), Err("bai".to_string()));
+    /// assert_eq!(sread.try_push_string("ya".to_string()), Ok(()));
+    /// assert_eq!(sread.queue, ["hai", "ya"]);
+    /// assert_eq!(sread.pop_string(), Some("hai".to_string()));
+    /// assert_eq!(sread.try_push_string("bai".to_string()), Ok(()));
+    /// assert_eq!(sread.queue, ["ya", "bai"]);
+    ///
+    /// sread.set_max_bytes(Some(4));
+    /// sread.queue.clear();
+    /// sread.push_string("abcd".to_string());
+    /// assert_eq!(sread.try_push_string("efgh".to_string()), Err("efgh".to_string()));
+    /// ```
+    pub fn try_push_string(&mut self, s: String) -> Result<(), String> {
+        if let Some(max) = self.max_bytes {
+            let queued: usize = self.queue.iter().map(String::len).sum();
+            if queued + s.len() > max {
+                return Err(s);
+            }
+        }
+        self.queue.push_back(s);
+        Ok(())
+    }
+
+    /// Split the whole stream at the last occurrence of `delim`, like [`str::rsplit_once`].
+    ///
+    /// On success, everything is consumed: the part before `delim` is returned as a new reader,
+    /// and the part after it as a `String`. Returns `None` if `delim` is not found, after moving
+    /// the backing reader's content into the queue.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["archive.tar", ".gz"].map(String::from)).into();
+    /// let (mut stem, ext) = sread.rsplit_once('.').unwrap();
+    /// assert_eq!(stem.concat(), "archive.tar");
+    /// assert_eq!(ext, "gz");
+    /// assert!(sread.is_empty());
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["archive".to_string()]).into();
+    /// assert_eq!(sread.rsplit_once('.'), None);
+    /// assert_eq!(sread.queue, ["archive"]);
+    /// ```
+    pub fn rsplit_once(&mut self, delim: char) -> Option<(Self, String)> {
+        while self.pull() {}
+        let content: String = self.queue.iter().map(String::as_str).collect();
+        let (head, tail) = content.rsplit_once(delim)?;
+        let head = VecDeque::from([head.to_string()]).into();
+        self.queue.clear();
+        self.consumed(Consumed::Strings, content.len());
+        Some((head, tail.to_string()))
+    }
+
+    /// Count the characters before the first `delim`, without consuming anything.
+    ///
+    /// Content is moved from the backing reader into the queue as needed. Returns `None` if
+    /// `delim` doesn't appear before the end of the stream.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("c;d".to_string());
+    /// sread.queue = VecDeque::from(["aé", "b"].map(String::from));
+    /// assert_eq!(sread.chars_until(';'), Some(4));
+    /// assert_eq!(sread.queue, ["aé", "b", "c;d"]);
+    /// assert_eq!(sread.chars_until('!'), None);
+    /// ```
+    pub fn chars_until(&mut self, delim: char) -> Option<usize> {
+        let mut count = 0;
+        let mut i = 0;
+        loop {
+            if i == self.queue.len() && !self.pull() {
+                return None;
+            }
+            match self.queue[i].find(delim) {
+                Some(pos) => return Some(count + self.queue[i][..pos].chars().count()),
+                None => count += self.queue[i].chars().count(),
+            }
+            i += 1;
+        }
+    }
+
+    /// Insert several segments at the front of the queue, keeping their order.
+    ///
+    /// Unlike calling [`StringWrite::shift_string`] repeatedly, which reverses them, the
+    /// segments will be popped in the order the iterator yields them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["c".to_string()]).into();
+    /// sread.shift_string_all(["a", "b"].map(String::from));
+    /// assert_eq!(sread.queue, ["a", "b", "c"]);
+    /// ```
+    pub fn shift_string_all(&mut self, items: impl IntoIterator<Item = String>) {
+        let mut queue: VecDeque<String> = items.into_iter().collect();
+        queue.append(&mut self.queue);
+        self.queue = queue;
+        self.split = 0;
+    }
+
+    /// Get the next grapheme cluster without consuming it.
+    ///
+    /// Empty queued segments are skipped. Grapheme clusters are not joined across segments.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = StringReader::from("e\u{301}x".to_string());
+    /// assert_eq!(sread.peek_grapheme(), Some("e\u{301}"));
+    /// assert_eq!(sread.peek_char(), Some('e'));
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    pub fn peek_grapheme(&self) -> Option<&str> {
+        use unicode_segmentation::UnicodeSegmentation;
+        (self
+            .queue
+            .iter()
+            .find(|s| !s.is_empty())
+            .map(String::as_str))
+        .or_else(|| self.reader.as_ref()?.peek_str())?
+        .graphemes(true)
+        .next()
+    }
+
+    /// Remove the next grapheme cluster and return it.
+    ///
+    /// Unlike [`StringReader::pop_char`], a combining sequence comes out as one unit. Empty
+    /// segments are skipped. Grapheme clusters are not joined across segments.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("e\u{301}x".to_string());
+    /// assert_eq!(sread.pop_grapheme(), Some("e\u{301}".to_string()));
+    /// assert_eq!(sread.pop_grapheme(), Some("x".to_string()));
+    /// assert_eq!(sread.pop_grapheme(), None);
+    ///
+    /// // the same input popped by code point instead
+    /// let mut sread: StringReader = StringReader::from("e\u{301}x".to_string());
+    /// assert_eq!(sread.pop_char(), Some('e'));
+    /// assert_eq!(sread.pop_char(), Some('\u{301}'));
+    /// assert_eq!(sread.pop_char(), Some('x'));
+    /// assert_eq!(sread.pop_char(), None);
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn pop_grapheme(&mut self) -> Option<String> {
+        use unicode_segmentation::UnicodeSegmentation;
+        while let Some(s) = self.peek_mut_string() {
+            let Some(len) = s.graphemes(true).next().map(str::len) else {
+                self.take_next();
+                continue;
+            };
+            let grapheme = if len == s.len() {
+                self.take_next().unwrap()
+            } else {
+                s.drain(..len).collect()
+            };
+            self.consumed(Consumed::Chars, len);
+            return Some(grapheme);
+        }
+        None
+    }
+
+    /// Replace each queued segment with its Unicode Normalization Form C.
+    ///
+    /// Only the queue is transformed; the backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut composed: StringReader = VecDeque::from(["caf\u{e9}".to_string()]).into();
+    /// let mut decomposed: StringReader = VecDeque::from(["cafe\u{301}".to_string()]).into();
+    /// assert_ne!(composed, decomposed);
+    /// composed.normalize_nfc();
+    /// decomposed.normalize_nfc();
+    /// assert_eq!(composed, decomposed);
+    /// assert_eq!(decomposed.queue, ["caf\u{e9}"]);
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub fn normalize_nfc(&mut self) {
+        use unicode_normalization::UnicodeNormalization;
+        (self.queue.iter_mut()).for_each(|s| *s = s.nfc().collect());
+    }
+
+    /// Replace each queued segment with its Unicode Normalization Form KC.
+    ///
+    /// Only the queue is transformed; the backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["\u{fb01}le", "e\u{301}"].map(String::from)).into();
+    /// sread.normalize_nfkc();
+    /// assert_eq!(sread.queue, ["file", "\u{e9}"]);
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub fn normalize_nfkc(&mut self) {
+        use unicode_normalization::UnicodeNormalization;
+        (self.queue.iter_mut()).for_each(|s| *s = s.nfkc().collect());
+    }
+
+    /// Get the byte length of the next line, including its `\n` if there is one.
+    ///
+    /// Content is moved from the backing reader into the queue as needed. Returns `None` if
+    /// the stream is empty.
+    fn next_line_len(&mut self) -> Option<usize> {
+        let mut len = 0;
+        let mut i = 0;
+        loop {
+            if i == self.queue.len() && !self.pull() {
+                return (len != 0).then_some(len);
+            }
+            if let Some(pos) = self.queue[i].find('\n') {
+                return Some(len + pos + 1);
+            }
+            len += self.queue[i].len();
+            i += 1;
+        }
+    }
+
+    /// Remove the next line and return it, without its `\n` or `\r\n`.
+    ///
+    /// Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b\r\nc\n", "d"].map(String::from)).into();
+    /// assert_eq!(sread.pop_line(), Some("ab".to_string()));
+    /// assert_eq!(sread.pop_line(), Some("c".to_string()));
+    /// assert_eq!(sread.pop_line(), Some("d".to_string()));
+    /// assert_eq!(sread.pop_line(), None);
+    /// ```
+    pub fn pop_line(&mut self) -> Option<String> {
+        let len = self.next_line_len()?;
+        let mut line = self.split_at_byte(len)?;
+        strip_line_ending(&mut line);
+        Some(line)
+    }
+
+    /// Get the next line without consuming it, without its `\n` or `\r\n`.
+    ///
+    /// Content is moved from the backing reader into the queue as needed. Returns `None` if
+    /// it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("b\r\nc".to_string());
+    /// sread.queue = VecDeque::from(["a".to_string()]);
+    /// assert_eq!(sread.peek_line(), Some("ab".to_string()));
+    /// assert_eq!(sread.peek_line(), Some("ab".to_string()));
+    /// assert_eq!(sread.queue, ["a", "b\r\nc"]);
+    /// assert_eq!(sread.pop_line(), Some("ab".to_string()));
+    /// assert_eq!(sread.peek_line(), Some("c".to_string()));
+    /// ```
+    pub fn peek_line(&mut self) -> Option<String> {
+        let len = self.next_line_len()?;
+        let mut line = String::with_capacity(len);
+        for s in &self.queue {
+            let take = (len - line.len()).min(s.len());
+            line.push_str(&s[..take]);
+            if line.len() == len {
+                break;
+            }
+        }
+        strip_line_ending(&mut line);
+        Some(line)
+    }
+
+    /// Save the current state of the reader, including a clone of the backing reader.
+    ///
+    /// Use [`StringReader::restore`] to roll back to it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::from("c".to_string());
+    /// sread.queue = VecDeque::from(["a", "b"].map(String::from));
+    /// let snap = sread.snapshot();
+    /// assert_eq!(sread.concat(), "abc");
+    /// sread.restore(snap);
+    /// assert_eq!(sread.pop_string(), Some("a".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("b".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("c".to_string()));
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> StringReaderSnapshot<R>
+    where
+        R: Clone,
+    {
+        StringReaderSnapshot {
+            queue: self.queue.clone(),
+            reader: Some(self.reader.clone()),
+            stats: self.stats,
+            split: self.split,
+        }
+    }
+
+    /// Save the current queue, leaving out the backing reader.
+    ///
+    /// Restoring it only rolls back the queue; whatever was consumed from the backing reader
+    /// in the meantime stays consumed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "b"].map(String::from)).into();
+    /// let snap = sread.snapshot_queue();
+    /// sread.pop_string();
+    /// sread.restore(snap);
+    /// assert_eq!(sread.queue, ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn snapshot_queue(&self) -> StringReaderSnapshot<R> {
+        StringReaderSnapshot {
+            queue: self.queue.clone(),
+            reader: None,
+            stats: self.stats,
+            split: self.split,
+        }
+    }
+
+    /// Roll back to a state saved with [`StringReader::snapshot`] or
+    /// [`StringReader::snapshot_queue`].
+    pub fn restore(&mut self, snap: StringReaderSnapshot<R>) {
+        self.queue = snap.queue;
+        self.stats = snap.stats;
+        self.split = snap.split;
+        if let Some(reader) = snap.reader {
+            self.reader = reader;
+        }
+    }
+
+    /// Remove the next segment without counting it in [`StringReader::stats`].
+    fn take_next(&mut self) -> Option<String> {
+        (self.queue.pop_front()).or_else(|| self.reader.as_mut().map(|r| r.pop_string())?)
+    }
+
+    /// Count `bytes` as consumed in [`StringReader::stats`].
+    ///
+    /// Every consumption is counted here, once. Moving content around internally isn't.
+    fn consumed(&mut self, kind: Consumed, bytes: usize) {
+        let count = match kind {
+            Consumed::Strings => &mut self.stats.strings,
+            Consumed::Chars => &mut self.stats.chars,
+            Consumed::Read => &mut self.stats.read,
+        };
+        *count += bytes as u64;
+        if !matches!(kind, Consumed::Read) {
+            self.split = 0;
+        }
+    }
+
+    /// Remove up to `n` bytes from the front, passing them to `sink` in order, and return how
+    /// many were removed.
+    ///
+    /// If `n` ends inside a character, the whole character stays at the front and `split`
+    /// remembers how much of it was already taken. Drained segments, including empty ones, are
+    /// removed. This is what [`std::io::Read`] and [`std::io::BufRead`] use.
+    fn take_bytes(&mut self, n: usize, mut sink: impl FnMut(&[u8])) -> usize {
+        let mut taken = 0;
+        while taken < n {
+            let split = self.split;
+            let Some(s) = self.peek_mut_string() else {
+                break;
+            };
+            let split = read_offset(s, split);
+            let rest = &s.as_bytes()[split..];
+            let room = n - taken;
+            if rest.len() <= room {
+                sink(rest);
+                taken += rest.len();
+                self.split = 0;
+                self.take_next();
+                continue;
+            }
+            let cut = split + room;
+            // ASCII fast path: an ASCII byte always starts a character, so no search is needed
+            let start = if s.as_bytes()[cut].is_ascii() {
+                cut
+            } else {
+                (0..=cut).rfind(|&i| s.is_char_boundary(i)).unwrap()
+            };
+            sink(&s.as_bytes()[split..cut]);
+            s.drain(..start);
+            self.split = cut - start;
+            taken = n;
+        }
+        self.consumed(Consumed::Read, taken);
+        taken
+    }
+
+    /// Remove the next character and return it.
+    ///
+    /// Empty segments are skipped. Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["€", "", "ab"].map(String::from)).into();
+    /// assert_eq!(sread.pop_char(), Some('€'));
+    /// assert_eq!(sread.pop_char(), Some('a'));
+    /// assert_eq!(sread.pop_char(), Some('b'));
+    /// assert_eq!(sread.pop_char(), None);
+    /// ```
+    pub fn pop_char(&mut self) -> Option<char> {
+        while let Some(s) = self.peek_mut_string() {
+            let Some(c) = s.chars().next() else {
+                self.take_next();
+                continue;
+            };
+            if c.len_utf8() == s.len() {
+                self.take_next();
+            } else {
+                s.drain(..c.len_utf8());
+            }
+            self.consumed(Consumed::Chars, c.len_utf8());
+            return Some(c);
+        }
+        None
+    }
+
+    /// Get how many bytes were consumed by each kind of operation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use std::io::Read;
+    /// use string_reader::{ConsumptionStats, StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["abc", "de", "fgh"].map(String::from)).into();
+    /// sread.pop_string();
+    /// sread.pop_char();
+    /// sread.read(&mut [0; 2]).unwrap();
+    /// let expected = ConsumptionStats { strings: 3, chars: 1, read: 2 };
+    /// assert_eq!(sread.stats(), expected);
+    /// sread.reset_stats();
+    /// assert_eq!(sread.stats(), ConsumptionStats::default());
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> ConsumptionStats {
+        self.stats
+    }
+
+    /// Set every count in [`StringReader::stats`] back to `0`.
+    pub fn reset_stats(&mut self) {
+        self.stats = ConsumptionStats::default();
+    }
+
+    /// Pop every segment and write its bytes to `w`, returning the total bytes written.
+    ///
+    /// # Errors
+    /// Returns the first error from `w`. The segment that was being written is dropped, and the
+    /// rest stay in the reader.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StrRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab", "€"].map(String::from)).into();
+    /// let mut out = Vec::new();
+    /// assert_eq!(sread.write_to(&mut out).unwrap(), 5);
+    /// assert_eq!(out, "ab€".as_bytes());
+    /// assert!(sread.is_empty());
+    /// ```
+    pub fn write_to<W: std::io::Write>(&mut self, w: &mut W) -> std::io::Result<usize> {
+        let mut total = 0;
+        while let Some(s) = self.pop_string() {
+            w.write_all(s.as_bytes())?;
+            total += s.len();
+        }
+        Ok(total)
+    }
+
+    /// Get the next byte without consuming it.
+    ///
+    /// For a multi-byte character, this is the first byte of its UTF-8 encoding. Empty queued
+    /// segments are skipped. Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::StringReader;
+    ///
+    /// assert_eq!(StringReader::<String>::from("GET".to_string()).peek_byte(), Some(b'G'));
+    /// assert_eq!(StringReader::<String>::from("€".to_string()).peek_byte(), Some(0xe2));
+    /// assert_eq!(StringReader::<String>::new().peek_byte(), None);
+    /// ```
+    #[must_use]
+    pub fn peek_byte(&self) -> Option<u8> {
+        (self.bytes().next()).or_else(|| self.reader.as_ref()?.peek_str()?.bytes().next())
+    }
+
+    /// Remove the queued segments matching `pred` and return them, in one pass.
+    ///
+    /// The other segments stay in place and in order. The backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["a", "1", "b", "22"].map(String::from)).into();
+    /// let numbers = sread.drain_filter(|s| s.chars().all(|c| c.is_ascii_digit()));
+    /// assert_eq!(numbers, ["1", "22"]);
+    /// assert_eq!(sread.queue, ["a", "b"]);
+    /// ```
+    pub fn drain_filter(&mut self, mut pred: impl FnMut(&str) -> bool) -> Vec<String> {
+        let mut drained = Vec::new();
+        let mut kept = VecDeque::with_capacity(self.queue.len());
+        for s in self.queue.drain(..) {
+            if pred(&s) {
+                drained.push(s);
+            } else {
+                kept.push_back(s);
+            }
+        }
+        self.queue = kept;
+        drained
+    }
+
+    /// Skip the next `n` characters, across segments.
+    ///
+    /// Like the unstable `Iterator::advance_by`, returns `Err` with the number of characters
+    /// that couldn't be skipped if the stream ends first.
+    ///
+    /// # Errors
+    /// Returns how many characters were left to skip when the stream ended.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["aé", "bcd"].map(String::from)).into();
+    /// assert_eq!(sread.advance_by(1), Ok(()));
+    /// assert_eq!(sread.peek_char(), Some('é'));
+    /// assert_eq!(sread.advance_by(2), Ok(()));
+    /// assert_eq!(sread.pop_string(), Some("cd".to_string()));
+    /// sread.queue = VecDeque::from(["xy".to_string()]);
+    /// assert_eq!(sread.advance_by(5), Err(3));
+    /// assert_eq!(sread.pop_string(), None);
+    /// ```
+    pub fn advance_by(&mut self, n: usize) -> Result<(), usize> {
+        let (mut left, mut bytes) = (n, 0);
+        while left > 0 {
+            let Some(s) = self.peek_mut_string() else {
+                break;
+            };
+            match s.char_indices().nth(left) {
+                Some((i, _)) => {
+                    s.drain(..i);
+                    bytes += i;
+                    left = 0;
+                }
+                None => {
+                    left -= s.chars().count();
+                    bytes += s.len();
+                    self.take_next();
+                }
+            }
+        }
+        self.consumed(Consumed::Chars, bytes);
+        if left == 0 {
+            Ok(())
+        } else {
+            Err(left)
+        }
+    }
+
+    /// Get the `(byte_len, char_len)` of the next segment without consuming it.
+    ///
+    /// Returns `None` if it's empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["abc", "é€"].map(String::from)).into();
+    /// assert_eq!(sread.next_segment_len(), Some((3, 3)));
+    /// sread.pop_string();
+    /// assert_eq!(sread.next_segment_len(), Some((5, 2)));
+    /// sread.pop_string();
+    /// assert_eq!(sread.next_segment_len(), None);
+    /// ```
+    #[must_use]
+    pub fn next_segment_len(&self) -> Option<(usize, usize)> {
+        self.peek_str().map(|s| (s.len(), s.chars().count()))
+    }
+
+    /// Yield each line including its `\n` or `\r\n`.
+    ///
+    /// The last line has no terminator if the stream doesn't end with one. Unlike
+    /// [`StringReader::pop_line`], the exact input can be rebuilt from the yielded lines.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a\r", "\nb\n", "c"].map(String::from)).into();
+    /// let lines: Vec<String> = sread.lines_with_endings().collect();
+    /// assert_eq!(lines, ["a\r\n", "b\n", "c"]);
+    /// ```
+    #[must_use]
+    pub fn lines_with_endings(self) -> LinesWithEndings<R> {
+        LinesWithEndings { inner: self }
+    }
+
+    /// Consume exactly `n` characters, across segments, and return them.
+    ///
+    /// This is all-or-nothing: unlike [`StringReader::advance_by`], which skips what it can,
+    /// nothing is consumed if the stream ends first. Content is moved from the backing reader
+    /// into the queue as needed.
+    ///
+    /// # Errors
+    /// Returns how many characters were available if that's fewer than `n`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["aé", "bc"].map(String::from)).into();
+    /// assert_eq!(sread.read_exact_chars(3), Ok("aéb".to_string()));
+    /// assert_eq!(sread.read_exact_chars(2), Err(1));
+    /// assert_eq!(sread.queue, ["c"]);
+    /// assert_eq!(sread.read_exact_chars(1), Ok("c".to_string()));
+    /// assert_eq!(sread.pop_string(), None);
+    /// ```
+    pub fn read_exact_chars(&mut self, n: usize) -> Result<String, usize> {
+        while self.chars().take(n).count() < n && self.pull() {}
+        let count = self.chars().take(n).count();
+        if count < n {
+            return Err(count);
+        }
+        let len = self.chars().take(n).map(char::len_utf8).sum();
+        Ok(self.split_at_byte(len).unwrap())
+    }
+
+    /// Merge consecutive queued segments until each is at least `target_bytes` long.
+    ///
+    /// Only the last segment can end up shorter. Segments already long enough are kept as they
+    /// are. This cuts the per-segment overhead of many tiny pushes. Only the queue is compacted;
+    /// the backing reader is left untouched.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let text = "the quick brown fox jumps over the lazy dog";
+    /// let mut sread: StringReader = text.chars().map(String::from).collect::<VecDeque<_>>().into();
+    /// sread.compact(16);
+    /// assert_eq!(sread.queue.len(), 3);
+    /// assert!(sread.queue.iter().rev().skip(1).all(|s| s.len() >= 16));
+    /// assert_eq!(sread.concat(), text);
+    /// ```
+    pub fn compact(&mut self, target_bytes: usize) {
+        let mut compacted = VecDeque::new();
+        let mut cur = String::new();
+        for s in self.queue.drain(..) {
+            if cur.is_empty() {
+                cur = s;
+            } else {
+                cur.push_str(&s);
+            }
+            if cur.len() >= target_bytes {
+                compacted.push_back(std::mem::take(&mut cur));
+            }
+        }
+        if !cur.is_empty() {
+            compacted.push_back(cur);
+        }
+        self.queue = compacted;
+    }
+
+    /// Consume `expected` if the stream starts with it, across segments.
+    ///
+    /// Returns whether it matched. On a mismatch, including a partial one, the stream is left
+    /// untouched. Content is moved from the backing reader into the queue as needed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["fo", "r x"].map(String::from)).into();
+    /// assert!(!sread.consume_str("fun"));
+    /// assert!(!sread.consume_str("for x y"));
+    /// assert_eq!(sread.queue, ["fo", "r x"]);
+    /// assert!(sread.consume_str("for"));
+    /// assert_eq!(sread.pop_string(), Some(" x".to_string()));
+    /// ```
+    pub fn consume_str(&mut self, expected: &str) -> bool {
+        while self.queue.iter().map(String::len).sum::<usize>() < expected.len() && self.pull() {}
+        self.bytes().take(expected.len()).eq(expected.bytes())
+            && self.split_at_byte(expected.len()).is_some()
+    }
+
+    /// Locate the byte at `offset` into the concatenated queue.
+    ///
+    /// Returns the index of the segment holding it and the byte offset inside that segment. An
+    /// offset on a boundary belongs to the segment starting there. Returns `None` if `offset`
+    /// is past the end. Only the queue is searched; the backing reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["abc", "", "de"].map(String::from)).into();
+    /// assert_eq!(sread.segment_at_byte(0), Some((0, 0)));
+    /// assert_eq!(sread.segment_at_byte(1), Some((0, 1)));
+    /// assert_eq!(sread.segment_at_byte(3), Some((2, 0)));
+    /// assert_eq!(sread.segment_at_byte(4), Some((2, 1)));
+    /// assert_eq!(sread.segment_at_byte(5), None);
+    /// ```
+    #[must_use]
+    pub fn segment_at_byte(&self, offset: usize) -> Option<(usize, usize)> {
+        let mut start = 0;
+        for (i, s) in self.queue.iter().enumerate() {
+            if offset < start + s.len() {
+                return Some((i, offset - start));
+            }
+            start += s.len();
+        }
+        None
+    }
+
+    /// Get the byte length of the shortest queued segment.
+    ///
+    /// Returns `None` if the queue is empty. Only the queue is measured; the backing reader is
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["ab", "c", "€de"].map(String::from)).into();
+    /// assert_eq!(sread.min_segment_len(), Some(1));
+    /// assert_eq!(sread.max_segment_len(), Some(5));
+    /// assert_eq!(StringReader::<String>::new().min_segment_len(), None);
+    /// assert_eq!(StringReader::<String>::new().max_segment_len(), None);
+    /// ```
+    #[must_use]
+    pub fn min_segment_len(&self) -> Option<usize> {
+        self.queue.iter().map(String::len).min()
+    }
+
+    /// Get the byte length of the longest queued segment.
+    ///
+    /// Returns `None` if the queue is empty. Only the queue is measured; the backing reader is
+    /// ignored.
+    #[must_use]
+    pub fn max_segment_len(&self) -> Option<usize> {
+        self.queue.iter().map(String::len).max()
+    }
+
+    /// Get the index of the last queued segment matching `pred`, searching from the back.
+    ///
+    /// Returns `None` if no segment matches. Only the queue is searched; the backing reader is
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", ";", "b", ";", "c"].map(String::from)).into();
+    /// assert_eq!(sread.rposition(|s| s == ";"), Some(3));
+    /// assert_eq!(sread.rposition(|s| s == ","), None);
+    /// ```
+    #[must_use]
+    pub fn rposition(&self, mut pred: impl FnMut(&str) -> bool) -> Option<usize> {
+        self.queue.iter().rposition(|s| pred(s))
+    }
+
+    /// Get the next `N` bytes as an array without consuming them, across segments.
+    ///
+    /// The bytes don't have to end on a character boundary. Content is moved from the backing
+    /// reader into the queue as needed. Returns `None` if fewer than `N` bytes are available.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("LF\r\n".to_string());
+    /// sread.queue = VecDeque::from(["PNG\r".to_string()]);
+    /// assert_eq!(sread.peek_array(), Some(*b"PNG\r"));
+    /// assert_eq!(sread.peek_array::<6>(), Some(*b"PNG\rLF"));
+    /// assert_eq!(sread.peek_array::<9>(), None);
+    /// assert_eq!(sread.queue, ["PNG\r", "LF\r\n"]);
+    /// ```
+    pub fn peek_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        while self.queue.iter().map(String::len).sum::<usize>() < N && self.pull() {}
+        let mut out = [0; N];
+        let mut bytes = self.bytes();
+        for b in &mut out {
+            *b = bytes.next()?;
+        }
+        Some(out)
+    }
+
+    /// Check if every queued segment is ASCII.
+    ///
+    /// If so, every byte offset is a character boundary, so byte-level operations like
+    /// [`std::io::Read`] can never split a character. Only the queue is checked; the backing
+    /// reader is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use std::io::Read;
+    /// use string_reader::StringReader;
+    ///
+    /// let text = "the quick brown fox jumps over the lazy dog\n".repeat(1000);
+    /// let mut sread: StringReader = text.split_inclusive(' ').map(String::from).collect::<VecDeque<_>>().into();
+    /// assert!(sread.all_ascii());
+    /// let mut out = Vec::new();
+    /// let mut buf = [0; 7];
+    /// loop {
+    ///     let n = sread.read(&mut buf).unwrap();
+    ///     if n == 0 {
+    ///         break;
+    ///     }
+    ///     assert!(n == buf.len() || sread.queue.is_empty());
+    ///     out.extend_from_slice(&buf[..n]);
+    /// }
+    /// assert_eq!(out, text.as_bytes());
+    ///
+    /// let sread: StringReader = VecDeque::from(["abc", "é"].map(String::from)).into();
+    /// assert!(!sread.all_ascii());
+    /// ```
+    #[must_use]
+    pub fn all_ascii(&self) -> bool {
+        self.queue.iter().all(|s| s.is_ascii())
+    }
+
+    /// Skip any characters in `delims`, then consume and return the run of characters up to the
+    /// next one, across segments.
+    ///
+    /// Returns `None` once only delimiters are left.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from([" ab", "c, d", ",,", "e ,"].map(String::from)).into();
+    /// let tokens: Vec<String> = std::iter::from_fn(|| sread.pop_token(&[',', ' '])).collect();
+    /// assert_eq!(tokens, ["abc", "d", "e"]);
+    /// assert!(sread.queue.is_empty());
+    /// ```
+    pub fn pop_token(&mut self, delims: &[char]) -> Option<String> {
+        self.consume_while_char(|c| delims.contains(&c));
+        let token = self.consume_while_char(|c| !delims.contains(&c));
+        (!token.is_empty()).then_some(token)
+    }
+
+    /// Consume a token enclosed in `quote`, across segments, and return its unescaped content.
+    ///
+    /// Inside the quotes, a `\` makes the next character literal, so `\` followed by `quote`
+    /// doesn't end the token and `\\` stands for a single `\`. Content is moved from the
+    /// backing reader into the queue as needed. Returns `None` and leaves the stream untouched
+    /// if it doesn't start with `quote` or the closing quote is missing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["\"ab", "c\" 'it\\'s'"].map(String::from)).into();
+    /// assert_eq!(sread.pop_quoted('"'), Some("abc".to_string()));
+    /// assert_eq!(sread.pop_quoted('\''), None);
+    /// assert_eq!(sread.pop_string(), Some(" 'it\\'s'".to_string()));
+    ///
+    /// let mut sread: StringReader = StringReader::from("'it\\'s' ok".to_string());
+    /// assert_eq!(sread.pop_quoted('\''), Some("it's".to_string()));
+    /// assert_eq!(sread.pop_string(), Some(" ok".to_string()));
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["\"ab", "c\\\""].map(String::from)).into();
+    /// assert_eq!(sread.pop_quoted('"'), None);
+    /// assert_eq!(sread.queue, ["\"ab", "c\\\""]);
+    /// ```
+    pub fn pop_quoted(&mut self, quote: char) -> Option<String> {
+        if self.peek_char()? != quote {
+            return None;
+        }
+        loop {
+            let mut out = String::new();
+            let mut len = quote.len_utf8();
+            let (mut escaped, mut closed) = (false, false);
+            for c in self.chars().skip(1) {
+                len += c.len_utf8();
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                    continue;
+                } else if c == quote {
+                    closed = true;
+                    break;
+                }
+                out.push(c);
+            }
+            if closed {
+                self.split_at_byte(len);
+                return Some(out);
+            }
+            if !self.pull() {
+                return None;
+            }
+        }
+    }
+}
+
+impl<R: StringRead> StrRead for StringReader<R> {
+    fn peek_str(&self) -> Option<&str> {
+        (self.queue.front().map(|s| s.as_str()))
+            .or_else(|| self.reader.as_ref().map(|r| r.peek_str())?)
+    }
+
+    // fn peek_mut_str<'a>(&'a mut self) -> Option<&'a mut str> {
+    //     (self.stack.last_mut().map(|s| s.as_mut_str()))
+    //         .or_else(|| self.reader.as_mut().map(|r| r.peek_mut_str())?)
+    // }
+
+    fn is_empty(&self) -> bool {
+        self.queue.is_empty() && self.reader.as_ref().is_none_or(|r| r.is_empty())
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        let len = self.queue.len();
+        let (lower, upper) = self.reader.as_ref().map_or((0, Some(0)), |r| r.len_hint());
+        (
+            len.saturating_add(lower),
+            upper.and_then(|u| u.checked_add(len)),
+        )
+    }
+}
+
+impl<R: StringRead> StringRead for StringReader<R> {
+    fn pop_string(&mut self) -> Option<String> {
+        let s = self.take_next()?;
+        self.consumed(Consumed::Strings, s.len());
+        Some(s)
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        (self.queue.front_mut()).or_else(|| self.reader.as_mut().map(|r| r.peek_mut_string())?)
+    }
+}
+
+impl<R: StringRead> std::io::Read for StringReader<R> {
+    /// Move as many bytes as fit into `buf`, across segments.
+    ///
+    /// `buf` is always filled unless the stream ends. If it ends in the middle of a character,
+    /// that character stays queued whole, and the next `read` or [`std::io::BufRead::fill_buf`]
+    /// picks up where this one stopped. Other methods still see the whole character. Consuming
+    /// it any other way, or shifting something in front of it, forgets where `read` stopped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use std::io::{BufRead, Read};
+    /// use string_reader::{StrRead, StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab€", "c"].map(String::from)).into();
+    /// let mut buf = [0; 3];
+    /// assert_eq!(sread.read(&mut buf).unwrap(), 3);
+    /// assert_eq!(&buf, b"ab\xe2");
+    /// assert_eq!(sread.fill_buf().unwrap(), b"\x82\xac");
+    /// assert_eq!(sread.read(&mut buf).unwrap(), 3);
+    /// assert_eq!(&buf, b"\x82\xacc");
+    /// assert_eq!(sread.read(&mut buf).unwrap(), 0);
+    ///
+    /// // a split character is still queued for the other methods
+    /// let mut sread: StringReader = StringReader::from("a€b".to_string());
+    /// let mut buf = [0; 2];
+    /// assert_eq!(sread.read(&mut buf).unwrap(), 2);
+    /// assert_eq!(&buf, b"a\xe2");
+    /// assert!(!sread.is_empty());
+    /// assert_eq!(sread.peek_str(), Some("€b"));
+    /// assert_eq!(sread.fill_buf().unwrap(), b"\x82\xacb");
+    /// assert_eq!(sread.pop_string(), Some("€b".to_string()));
+    /// assert!(sread.is_empty());
+    ///
+    /// let text = "é€😀 ok".repeat(100);
+    /// let sread: StringReader = StringReader::from(text.clone());
+    /// let bytes: Vec<u8> = sread.bytes().collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(bytes, text.as_bytes());
+    /// let segments: VecDeque<String> = text.split_inclusive(' ').map(String::from).collect();
+    /// let mut sread: StringReader = segments.into();
+    /// let mut out = Vec::new();
+    /// let mut buf = [0; 7];
+    /// loop {
+    ///     let n = sread.read(&mut buf).unwrap();
+    ///     if n == 0 {
+    ///         break;
+    ///     }
+    ///     out.extend_from_slice(&buf[..n]);
+    /// }
+    /// assert_eq!(out, text.as_bytes());
+    ///
+    /// // the ASCII fast path gives the same result as splitting on char boundaries
+    /// let text = "the quick brown fox jumps over the lazy dog\n".repeat(1000);
+    /// let segments: VecDeque<String> = text.split_inclusive(' ').map(String::from).collect();
+    /// let mut fast: StringReader = segments.clone().into();
+    /// let mut general: StringReader = segments.into();
+    /// let mut buf = [0; 7];
+    /// loop {
+    ///     let n = fast.read(&mut buf).unwrap();
+    ///     assert_eq!(&buf[..n], general.pop_bytes(7).as_bytes());
+    ///     if n == 0 {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut pos = 0;
+        Ok(self.take_bytes(buf.len(), |bytes| {
+            buf[pos..pos + bytes.len()].copy_from_slice(bytes);
+            pos += bytes.len();
+        }))
+    }
+}
+
+impl<R: StringRead> std::io::BufRead for StringReader<R> {
+    /// Return the rest of the front segment.
+    ///
+    /// Empty segments are skipped, so an empty slice only comes back once the stream has ended.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use std::io::{BufRead, Read};
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = VecDeque::from(["ab€", "", "cd\n"].map(String::from)).into();
+    /// let mut buf = [0; 3];
+    /// sread.read_exact(&mut buf).unwrap();
+    /// let mut line = Vec::new();
+    /// sread.read_until(b'\n', &mut line).unwrap();
+    /// assert_eq!(line, b"\x82\xaccd\n");
+    /// assert_eq!(sread.read_until(b'\n', &mut line).unwrap(), 0);
+    /// assert!(sread.fill_buf().unwrap().is_empty());
+    /// ```
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        // an empty slice means EOF, so drained segments must not be returned
+        while self.peek_str() == Some("") {
+            self.take_next();
+        }
+        let split = self.split;
+        Ok(self
+            .peek_str()
+            .map_or(&[], |s| &s.as_bytes()[read_offset(s, split)..]))
+    }
+
+    /// Consume exactly `amt` bytes, or everything if there are fewer.
+    ///
+    /// Like [`std::io::Read::read`], a character split by `amt` stays queued whole.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::io::BufRead;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::from("aé!".to_string());
+    /// assert_eq!(sread.fill_buf().unwrap(), "aé!".as_bytes());
+    /// sread.consume(2);
+    /// assert_eq!(sread.fill_buf().unwrap(), b"\xa9!");
+    /// sread.consume(1);
+    /// assert_eq!(sread.fill_buf().unwrap(), b"!");
+    /// ```
+    fn consume(&mut self, amt: usize) {
+        self.take_bytes(amt, |_| {});
+    }
+}
+
+// NOTE: comparisons are based on the queued content only, ignoring how it's split into segments.
+// The backing reader is not compared.
+
+impl<R: StringRead> PartialEq for StringReader<R> {
+    /// Check if the concatenated queued content is equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let a: StringReader = VecDeque::from(["ab", "c"].map(String::from)).into();
+    /// let b: StringReader = VecDeque::from(["a", "bc"].map(String::from)).into();
+    /// assert_eq!(a, b);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes().eq(other.bytes())
+    }
+}
+
+impl<R: StringRead> Eq for StringReader<R> {}
+
+impl<R: StringRead> PartialEq<str> for StringReader<R> {
+    /// Check if the concatenated queued content is equal to `other`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["ab", "c"].map(String::from)).into();
+    /// assert!(sread == *"abc");
+    /// assert_eq!(sread, "abc");
+    /// assert_eq!(sread, "abc".to_string());
+    /// assert_ne!(sread, "ab");
+    /// assert_ne!(sread, "abcd".to_string());
+    /// ```
+    fn eq(&self, other: &str) -> bool {
+        self.bytes().eq(other.bytes())
+    }
+}
+
+impl<R: StringRead> PartialEq<&str> for StringReader<R> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl<R: StringRead> PartialEq<String> for StringReader<R> {
+    fn eq(&self, other: &String) -> bool {
+        *self == **other
+    }
+}
+
+impl<R: StringRead> PartialOrd for StringReader<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<R: StringRead> Ord for StringReader<R> {
+    /// Compare the concatenated queued content lexicographically.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let a: StringReader = VecDeque::from(["ab", "c"].map(String::from)).into();
+    /// let b: StringReader = VecDeque::from(["a", "bd"].map(String::from)).into();
+    /// let c: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+    /// assert_eq!(a.cmp(&b), Ordering::Less);
+    /// assert_eq!(b.cmp(&a), Ordering::Greater);
+    /// assert_eq!(a.cmp(&c), Ordering::Equal);
+    /// ```
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bytes().cmp(other.bytes())
+    }
+}
+
+/// A wrapper hashing a [`StringReader`] by its queued content, ignoring segmentation.
+///
+/// `StringReader` itself doesn't implement `Hash`; wrap it in this to use it as a `HashMap` or
+/// `HashSet` key. The backing reader is not hashed or compared.
+///
+/// # Examples
+/// ```rust
+/// use std::collections::{HashSet, VecDeque};
+/// use string_reader::{ContentEq, StringReader};
+///
+/// let a: StringReader = VecDeque::from(["ab", "c"].map(String::from)).into();
+/// let b: StringReader = VecDeque::from(["a", "bc"].map(String::from)).into();
+/// let mut set = HashSet::new();
+/// set.insert(ContentEq(&a));
+/// assert!(set.contains(&ContentEq(&b)));
+/// assert!(!set.insert(ContentEq(&b)));
+/// ```
+#[derive(Debug)]
+pub struct ContentEq<'a, R: StringRead = String>(pub &'a StringReader<R>);
+
+// NOTE: not derived, since that would require R to impl the traits too
+
+impl<R: StringRead> Clone for ContentEq<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: StringRead> Copy for ContentEq<'_, R> {}
+
+impl<R: StringRead> PartialEq for ContentEq<'_, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<R: StringRead> Eq for ContentEq<'_, R> {}
+
+impl<R: StringRead> std::hash::Hash for ContentEq<'_, R> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.bytes().for_each(|b| state.write_u8(b));
+        state.write_u8(0xff);
+    }
+}
+
+impl<R: StringRead> std::fmt::Write for StringReader<R> {
+    /// Push `s` to the back of the queue.
+    ///
+    /// Formatting machinery may call this several times for a single `write!`, so one `write!`
+    /// can produce more than one segment. Fails if `s` would go over the limit set with
+    /// [`StringReader::set_max_bytes`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::fmt::Write;
+    /// use string_reader::StringReader;
+    ///
+    /// let mut sread: StringReader = StringReader::new();
+    /// write!(sread, "{}-{}", 1, 2).unwrap();
+    /// assert_eq!(sread.concat(), "1-2");
+    /// ```
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.try_push_string(s.to_string())
+            .map_err(|_| std::fmt::Error)
+    }
+}
+
+impl<R: StringRead> Extend<char> for StringReader<R> {
+    /// Collect the characters into one new segment at the back of the queue.
+    ///
+    /// Each call to `extend` makes its own segment. Nothing is pushed if there are no
+    /// characters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader = StringReader::new();
+    /// sread.extend(['a', 'b', 'c']);
+    /// sread.extend("de".chars());
+    /// assert_eq!(sread.pop_string(), Some("abc".to_string()));
+    /// assert_eq!(sread.pop_string(), Some("de".to_string()));
+    /// ```
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        let s: String = iter.into_iter().collect();
+        if !s.is_empty() {
+            self.push_string(s);
+        }
+    }
+}
+
+impl StrRead for str {
+    fn peek_str(&self) -> Option<&str> {
+        Some(self)
+    }
+
+    // fn peek_mut_str<'a>(&'a mut self) -> Option<&'a mut str> {
+    //     Some(self)
+    // }
+}
+impl RealStrRead for str {
+    fn pop_str(&mut self) -> Option<&str> {
+        Some(self)
+    }
+}
+impl<R: StrRead + ?Sized> StrRead for Box<R> {
+    fn peek_str(&self) -> Option<&str> {
+        (**self).peek_str()
+    }
+
+    // fn peek_mut_str<'a>(&'a mut self) -> Option<&'a mut str> {
+    //     (**self).peek_mut_str()
+    // }
+}
+impl<R: RealStrRead + ?Sized> RealStrRead for Box<R> {
+    fn pop_str(&mut self) -> Option<&str> {
+        (**self).pop_str()
+    }
+}
+impl StrRead for &str {
+    fn peek_str(&self) -> Option<&str> {
+        (!str::is_empty(self)).then_some(self)
+    }
+}
+impl RealStrRead for &str {
+    /// Take the whole `&str`, leaving an empty one behind.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{RealStrRead, StrRead, StrReader};
+    ///
+    /// let mut sread: StrReader<&str> = StrReader::from("hai");
+    /// assert_eq!(sread.peek_str(), Some("hai"));
+    /// assert_eq!(sread.pop_str(), Some("hai"));
+    /// assert_eq!(sread.pop_str(), None);
+    /// ```
+    fn pop_str(&mut self) -> Option<&str> {
+        (!str::is_empty(self)).then(|| std::mem::take(self))
+    }
+}
+impl StrRead for std::borrow::Cow<'_, str> {
+    fn peek_str(&self) -> Option<&str> {
+        (!str::is_empty(self)).then_some(self)
+    }
+}
+impl StringRead for std::borrow::Cow<'_, str> {
+    /// Take the whole `Cow` as a `String`, leaving an empty one behind.
+    ///
+    /// A borrowed `Cow` is copied into a new `String`. An empty `Cow` counts as exhausted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use string_reader::{StrRead, StringRead, StringReader};
+    ///
+    /// let mut sread: StringReader<Cow<str>> = StringReader::from(Cow::Borrowed("hai"));
+    /// assert_eq!(sread.concat(), "hai");
+    /// assert!(sread.is_empty());
+    ///
+    /// let mut sread: StringReader<Cow<str>> = StringReader::from(Cow::Owned("bai".to_string()));
+    /// sread.map_string(|s| s.push('!'));
+    /// assert_eq!(sread.pop_string(), Some("bai!".to_string()));
+    /// assert_eq!(sread.pop_string(), None);
+    /// ```
+    fn pop_string(&mut self) -> Option<String> {
+        (!self.is_empty()).then(|| std::mem::take(self).into_owned())
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        (!self.is_empty()).then(|| self.to_mut())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct StrReader<'a, R: RealStrRead = Box<str>> {
+    pub queue: VecDeque<&'a str>,
+    pub reader: Option<R>,
+}
+
+impl<'a, R: RealStrRead> Default for StrReader<'a, R> {
+    fn default() -> Self {
+        Self {
+            queue: Default::default(),
+            reader: None,
+        }
+    }
+}
+
+impl<'a, R: RealStrRead> From<R> for StrReader<'a, R> {
+    fn from(value: R) -> Self {
+        Self {
+            queue: Default::default(),
+            reader: Some(value),
+        }
+    }
+}
+
+impl<'a, R: RealStrRead> From<VecDeque<&'a str>> for StrReader<'a, R> {
+    fn from(value: VecDeque<&'a str>) -> Self {
+        Self {
+            queue: value,
+            reader: None,
+        }
+    }
+}
+
+impl<'a, R: RealStrRead> StrReader<'a, R> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get a reference to the backing reader, if there is one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{RealStrRead, StrReader};
+    ///
+    /// let mut sread: StrReader<&str> = StrReader::from("hai");
+    /// *sread.reader_mut().unwrap() = "bai";
+    /// assert_eq!(sread.reader_ref(), Some(&"bai"));
+    /// assert_eq!(sread.pop_str(), Some("bai"));
+    /// ```
+    #[must_use]
+    pub fn reader_ref(&self) -> Option<&R> {
+        self.reader.as_ref()
+    }
+
+    /// Get a mutable reference to the backing reader, if there is one.
+    pub fn reader_mut(&mut self) -> Option<&mut R> {
+        self.reader.as_mut()
+    }
+}
+
+impl<R: RealStrRead> StrReader<'static, R> {
+    /// Leak `s` and push it to the back of the queue.
+    ///
+    /// This intentionally leaks memory: the `String` is never freed. It's an escape hatch for
+    /// building a `StrReader<'static>` out of owned strings, and should not be used in loops
+    /// that run for a long time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{RealStrRead, StrReader};
+    ///
+    /// let mut sread: StrReader<'static> = StrReader::new();
+    /// for n in 0..2 {
+    ///     sread.push_leaked(format!("item {n}"));
+    /// }
+    /// assert_eq!(sread.pop_str(), Some("item 0"));
+    /// assert_eq!(sread.pop_str(), Some("item 1"));
+    /// assert_eq!(sread.pop_str(), None);
+    /// ```
+    pub fn push_leaked(&mut self, s: String) {
+        self.queue.push_back(s.leak());
+    }
+}
+
+impl<R: RealStrRead> std::io::Write for StrReader<'static, R> {
+    /// Push the written bytes as a new segment, leaking them like [`StrReader::push_leaked`].
+    ///
+    /// Every write leaks its content for the rest of the program, so this should only be used
+    /// for output of bounded size.
+    ///
+    /// # Errors
+    /// Returns [`std::io::ErrorKind::InvalidData`] if `buf` isn't valid UTF-8 on its own. A
+    /// character split across two writes counts as invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::io::Write;
+    /// use string_reader::StrReader;
+    ///
+    /// let mut sread: StrReader<'static> = StrReader::new();
+    /// write!(sread, "hai").unwrap();
+    /// sread.write_all("€".as_bytes()).unwrap();
+    /// assert!(sread.write(&[0xe2, 0x82]).is_err());
+    /// // the leaked segments outlive the reader
+    /// let popped: Vec<&'static str> = sread.into_iter().collect();
+    /// assert_eq!(popped, ["hai", "€"]);
+    /// ```
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let s = std::str::from_utf8(buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        self.push_leaked(s.to_string());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, R: RealStrRead> StrRead for StrReader<'a, R> {
+    fn peek_str(&self) -> Option<&str> {
+        (self.queue.front().copied()).or_else(|| self.reader.as_ref().and_then(|r| r.peek_str()))
+    }
+
+    // fn peek_mut_str<'b>(&'b mut self) -> Option<&'b mut str> {
+    //     (self.stack.last_mut().map(|s| s.as_mut()))
+    //         .or_else(|| self.reader.as_mut().map(|r| r.peek_mut_str())?)
+    // }
+
+    fn is_empty(&self) -> bool {
+        self.queue.is_empty() && self.reader.as_ref().is_none_or(|r| r.is_empty())
+    }
+}
+
+impl<'a, R: RealStrRead> RealStrRead for StrReader<'a, R> {
+    fn pop_str(&mut self) -> Option<&str> {
+        self.queue
+            .pop_front()
+            .or_else(|| self.reader.as_mut().and_then(|r| r.pop_str()))
+    }
+}
+
+impl<'r, R: RealStrRead> StrWrite<'r> for StrReader<'r, R> {
+    fn push_str(&mut self, s: &'r str) {
+        self.queue.push_back(s);
+    }
+
+    fn shift_str(&mut self, s: &'r str) {
         self.queue.push_front(s);
     }
 }
 
+impl<'a, R: RealStrRead> IntoIterator for StrReader<'a, R> {
+    type Item = &'a str;
+    type IntoIter = std::collections::vec_deque::IntoIter<&'a str>;
+
+    /// Iterate over the queued `&str`s.
+    ///
+    /// The backing reader can only lend `&str`s that live as long as itself, so it is dropped
+    /// and its content is not iterated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_reader::{StrReader, StrWrite};
+    ///
+    /// let mut sread: StrReader = StrReader::new();
+    /// sread.push_str("hai");
+    /// sread.push_str("bai");
+    /// let mut popped = Vec::new();
+    /// for s in sread {
+    ///     popped.push(s);
+    /// }
+    /// assert_eq!(popped, ["hai", "bai"]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.queue.into_iter()
+    }
+}
+
 // impl<'r, R: RealStrRead> std::io::Read for StrReader<'r, R> {
 //     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
 //         let mut l = buf.len();
@@ -357,7 +3457,7 @@ impl<'r, R: RealStrRead> StrWrite<'r> for StrReader<'r, R> {
 //         while let Some(s) = self.pop_str() {
 //             let slen = s.len();
 //             if slen > l {
-//                 buf[pos..].copy_from_slice(s[..l].as_bytes());
+//                 buf[pos..].copy_from_slice(&s.as_bytes()[..l]);
 //                 self.shift_str(&s[l..]);
 //                 return Ok(buf.len());
 //             }
@@ -376,6 +3476,635 @@ impl<R: StringRead> StringWrite for StringReader<R> {
     }
 
     fn shift_string(&mut self, s: String) {
+        self.split = 0;
         self.queue.push_front(s);
     }
 }
+
+/// A reader that yields every `step`-th segment of a [`StringReader`].
+///
+/// See [`StringReader::step_by`].
+#[derive(Clone, Debug)]
+pub struct StepByReader<R: StringRead = String> {
+    inner: StringReader<R>,
+    step: usize,
+}
+
+impl<R: StringRead> StrRead for StepByReader<R> {
+    fn peek_str(&self) -> Option<&str> {
+        self.inner.peek_str()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<R: StringRead> StringRead for StepByReader<R> {
+    fn pop_string(&mut self) -> Option<String> {
+        let s = self.inner.pop_string()?;
+        (1..self.step).for_each(|_| drop(self.inner.pop_string()));
+        Some(s)
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        self.inner.peek_mut_string()
+    }
+}
+
+impl<R: StringRead> Iterator for StepByReader<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.pop_string()
+    }
+}
+
+/// A reader that pairs segments from two [`StringReader`]s.
+///
+/// See [`StringReader::zip`].
+#[derive(Clone, Debug)]
+pub struct ZipReader<R: StringRead = String, S: StringRead = String> {
+    a: StringReader<R>,
+    b: StringReader<S>,
+}
+
+impl<R: StringRead, S: StringRead> ZipReader<R, S> {
+    /// Get back both readers, including whatever was left unpaired.
+    #[must_use]
+    pub fn into_inner(self) -> (StringReader<R>, StringReader<S>) {
+        (self.a, self.b)
+    }
+}
+
+impl<R: StringRead, S: StringRead> Iterator for ZipReader<R, S> {
+    type Item = (String, String);
+
+    fn next(&mut self) -> Option<(String, String)> {
+        if self.a.is_empty() || self.b.is_empty() {
+            return None;
+        }
+        Some((self.a.pop_string()?, self.b.pop_string()?))
+    }
+}
+
+/// A reader that yields segments along with their indices.
+///
+/// See [`StringReader::enumerate`].
+#[derive(Clone, Debug)]
+pub struct EnumerateReader<R: StringRead = String> {
+    inner: StringReader<R>,
+    count: usize,
+}
+
+impl<R: StringRead> Iterator for EnumerateReader<R> {
+    type Item = (usize, String);
+
+    fn next(&mut self) -> Option<(usize, String)> {
+        let s = self.inner.pop_string()?;
+        let i = self.count;
+        self.count += 1;
+        Some((i, s))
+    }
+}
+
+/// A reader that calls a closure on each segment that passes through.
+///
+/// See [`StringReader::inspect`].
+#[derive(Clone, Debug)]
+pub struct InspectReader<R: StringRead, F: FnMut(&str)> {
+    inner: StringReader<R>,
+    f: F,
+}
+
+impl<R: StringRead, F: FnMut(&str)> StrRead for InspectReader<R, F> {
+    fn peek_str(&self) -> Option<&str> {
+        self.inner.peek_str()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<R: StringRead, F: FnMut(&str)> StringRead for InspectReader<R, F> {
+    fn pop_string(&mut self) -> Option<String> {
+        let s = self.inner.pop_string()?;
+        (self.f)(&s);
+        Some(s)
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        self.inner.peek_mut_string()
+    }
+}
+
+impl<R: StringRead, F: FnMut(&str)> Iterator for InspectReader<R, F> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.pop_string()
+    }
+}
+
+/// A reader that endlessly repeats the segments of a [`StringReader`].
+///
+/// See [`StringReader::cycle`].
+#[derive(Clone, Debug)]
+pub struct CycleReader<R: StringRead = String> {
+    inner: StringReader<R>,
+    seen: Vec<String>,
+    pos: usize,
+}
+
+impl<R: StringRead> StrRead for CycleReader<R> {
+    fn peek_str(&self) -> Option<&str> {
+        (self.inner.peek_str()).or_else(|| self.seen.get(self.pos).map(|s| s.as_str()))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty() && self.seen.is_empty()
+    }
+}
+
+impl<R: StringRead> StringRead for CycleReader<R> {
+    fn pop_string(&mut self) -> Option<String> {
+        if let Some(s) = self.inner.pop_string() {
+            self.seen.push(s.clone());
+            return Some(s);
+        }
+        let s = self.seen.get(self.pos)?.clone();
+        self.pos = (self.pos + 1) % self.seen.len();
+        Some(s)
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        if !self.inner.is_empty() {
+            return self.inner.peek_mut_string();
+        }
+        self.seen.get_mut(self.pos)
+    }
+}
+
+impl<R: StringRead> Iterator for CycleReader<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.pop_string()
+    }
+}
+
+/// An iterator over the lines of a [`StringReader`], terminators included.
+///
+/// See [`StringReader::lines_with_endings`].
+#[derive(Clone, Debug)]
+pub struct LinesWithEndings<R: StringRead = String> {
+    inner: StringReader<R>,
+}
+
+impl<R: StringRead> Iterator for LinesWithEndings<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let len = self.inner.next_line_len()?;
+        self.inner.split_at_byte(len)
+    }
+}
+
+/// Drain each reader in turn into a single flat [`StringReader`].
+///
+/// # Examples
+/// ```rust
+/// use std::collections::VecDeque;
+/// use string_reader::{concat_readers, StringReader};
+///
+/// let readers: Vec<StringReader> = vec![
+///     VecDeque::from(["a", "b"].map(String::from)).into(),
+///     VecDeque::from(["c"].map(String::from)).into(),
+///     VecDeque::from(["d", "e"].map(String::from)).into(),
+/// ];
+/// let sread = concat_readers(readers);
+/// assert_eq!(sread.queue, ["a", "b", "c", "d", "e"]);
+/// ```
+#[must_use]
+pub fn concat_readers<R: StringRead>(readers: Vec<StringReader<R>>) -> StringReader {
+    readers.into_iter().sum()
+}
+
+/// Pop everything from any [`StringRead`] and concatenate it into one `String`.
+///
+/// # Examples
+/// ```rust
+/// use std::collections::VecDeque;
+/// use string_reader::{drain_to_string, StrRead, StringReader};
+///
+/// let mut sread: StringReader = VecDeque::from(["a", "b", "c"].map(String::from)).into();
+/// assert_eq!(drain_to_string(&mut sread), "abc");
+/// assert!(sread.is_empty());
+///
+/// let mut s = "hai".to_string();
+/// assert_eq!(drain_to_string(&mut s), "hai");
+/// assert!(s.is_empty());
+/// ```
+pub fn drain_to_string(r: &mut impl StringRead) -> String {
+    let mut out = String::new();
+    while let Some(s) = r.pop_string() {
+        out.push_str(&s);
+    }
+    out
+}
+
+/// An iterator popping every segment of a [`StringReader`].
+///
+/// See [`StringReader::into_strings`].
+#[derive(Clone, Debug)]
+pub struct IntoStrings<R: StringRead = String> {
+    inner: StringReader<R>,
+}
+
+impl<R: StringRead> Iterator for IntoStrings<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.inner.pop_string()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.len_hint()
+    }
+}
+
+impl<R: StringRead> DoubleEndedIterator for IntoStrings<R> {
+    /// Pop the last queued segment.
+    ///
+    /// The backing reader can only be read from the front, so its content is never returned
+    /// here, unless it was already moved into the queue. Once the queue is empty, this returns
+    /// `None` even if [`Iterator::next`] would still yield more.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::StringReader;
+    ///
+    /// let sread: StringReader = VecDeque::from(["a", "b", "c", "d"].map(String::from)).into();
+    /// let mut strings = sread.into_strings();
+    /// assert_eq!(strings.next(), Some("a".to_string()));
+    /// assert_eq!(strings.next_back(), Some("d".to_string()));
+    /// assert_eq!(strings.next_back(), Some("c".to_string()));
+    /// assert_eq!(strings.next(), Some("b".to_string()));
+    /// assert_eq!(strings.next_back(), None);
+    /// assert_eq!(strings.next(), None);
+    ///
+    /// let sread: StringReader = StringReader::from("x".to_string());
+    /// assert_eq!(sread.into_strings().next_back(), None);
+    /// ```
+    fn next_back(&mut self) -> Option<String> {
+        let s = self.inner.queue.pop_back()?;
+        self.inner.consumed(Consumed::Strings, s.len());
+        Some(s)
+    }
+}
+
+impl<R: StringRead> IntoIterator for StringReader<R> {
+    type Item = String;
+    type IntoIter = IntoStrings<R>;
+
+    fn into_iter(self) -> IntoStrings<R> {
+        self.into_strings()
+    }
+}
+
+impl<R: StringRead> std::iter::Sum<StringReader<R>> for StringReader {
+    /// Drain each reader in turn into a single flat reader, like [`concat_readers`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use string_reader::{StringRead, StringReader};
+    ///
+    /// let readers: Vec<StringReader> = vec![
+    ///     VecDeque::from(["a", "b"].map(String::from)).into(),
+    ///     StringReader::from("c".to_string()),
+    ///     VecDeque::from(["d"].map(String::from)).into(),
+    /// ];
+    /// let mut sread: StringReader = readers.into_iter().sum();
+    /// let popped: Vec<String> = std::iter::from_fn(|| sread.pop_string()).collect();
+    /// assert_eq!(popped, ["a", "b", "c", "d"]);
+    /// ```
+    fn sum<I: Iterator<Item = StringReader<R>>>(iter: I) -> Self {
+        let mut queue = VecDeque::new();
+        for mut r in iter {
+            while let Some(s) = r.pop_string() {
+                queue.push_back(s);
+            }
+        }
+        queue.into()
+    }
+}
+
+/// A [`StringRead`] that pops the lines of a [`std::io::BufRead`], terminators included.
+///
+/// By default, the stream silently ends at the first I/O error. With
+/// [`LineReader::collecting_errors`], errors are kept in [`LineReader::errors`] instead and
+/// reading carries on, until two errors occur in a row.
+///
+/// # Examples
+/// ```rust
+/// use std::io::Cursor;
+/// use string_reader::{LineReader, StringRead, StringReader};
+///
+/// let mut sread = StringReader::from(LineReader::new(Cursor::new("a\nb")));
+/// assert_eq!(sread.pop_string(), Some("a\n".to_string()));
+/// assert_eq!(sread.pop_string(), Some("b".to_string()));
+/// assert_eq!(sread.pop_string(), None);
+/// ```
+#[derive(Debug)]
+pub struct LineReader<B: std::io::BufRead> {
+    inner: B,
+    next: Option<String>,
+    collect_errors: bool,
+    errors: Vec<std::io::Error>,
+}
+
+impl<B: std::io::BufRead> LineReader<B> {
+    /// Create a reader that ends at the first I/O error.
+    pub fn new(inner: B) -> Self {
+        Self::with_mode(inner, false)
+    }
+
+    /// Create a reader that collects I/O errors instead of ending at them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::io::{BufReader, Error, Read};
+    /// use string_reader::{LineReader, StringRead};
+    ///
+    /// struct Flaky(Vec<&'static [u8]>, bool);
+    /// impl Read for Flaky {
+    ///     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    ///         self.1 = !self.1;
+    ///         if self.1 {
+    ///             return Err(Error::other("flaky"));
+    ///         }
+    ///         let Some(chunk) = self.0.pop() else { return Ok(0) };
+    ///         buf[..chunk.len()].copy_from_slice(chunk);
+    ///         Ok(chunk.len())
+    ///     }
+    /// }
+    ///
+    /// let flaky = Flaky(vec![b"b\n", b"a\n"], false);
+    /// let mut lines = LineReader::collecting_errors(BufReader::new(flaky));
+    /// assert_eq!(lines.pop_string(), Some("a\n".to_string()));
+    /// assert_eq!(lines.pop_string(), Some("b\n".to_string()));
+    /// assert_eq!(lines.pop_string(), None);
+    /// assert_eq!(lines.errors().len(), 3);
+    /// ```
+    pub fn collecting_errors(inner: B) -> Self {
+        Self::with_mode(inner, true)
+    }
+
+    fn with_mode(inner: B, collect_errors: bool) -> Self {
+        let mut r = Self {
+            inner,
+            next: None,
+            collect_errors,
+            errors: Vec::new(),
+        };
+        r.fill();
+        r
+    }
+
+    /// Get the I/O errors collected so far.
+    ///
+    /// This is always empty unless the reader was created with
+    /// [`LineReader::collecting_errors`].
+    #[must_use]
+    pub fn errors(&self) -> &[std::io::Error] {
+        &self.errors
+    }
+
+    /// Read the next line into the lookahead.
+    fn fill(&mut self) {
+        let mut line = String::new();
+        let mut failed = false;
+        self.next = loop {
+            match self.inner.read_line(&mut line) {
+                Ok(0) if line.is_empty() => break None,
+                Ok(_) => break Some(line),
+                Err(e) if self.collect_errors && !failed => {
+                    self.errors.push(e);
+                    failed = true;
+                }
+                Err(e) => {
+                    if self.collect_errors {
+                        self.errors.push(e);
+                    }
+                    break (!line.is_empty()).then_some(line);
+                }
+            }
+        };
+    }
+}
+
+impl<B: std::io::BufRead> StrRead for LineReader<B> {
+    fn peek_str(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+}
+
+impl<B: std::io::BufRead> StringRead for LineReader<B> {
+    fn pop_string(&mut self) -> Option<String> {
+        let s = self.next.take()?;
+        self.fill();
+        Some(s)
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        self.next.as_mut()
+    }
+}
+
+/// A [`StringRead`] that pops the items of an iterator.
+///
+/// One item is always read ahead so that it can be peeked.
+///
+/// # Examples
+/// ```rust
+/// use std::collections::VecDeque;
+/// use string_reader::{IterReader, StringReader};
+///
+/// let items = vec!["c", "d", "e"].into_iter().map(String::from);
+/// let mut sread = StringReader::from(IterReader::new(items));
+/// sread.queue = VecDeque::from(["a", "b"].map(String::from));
+/// let strings = sread.into_strings();
+/// assert_eq!(strings.size_hint(), (5, Some(5)));
+/// assert_eq!(strings.collect::<Vec<_>>(), ["a", "b", "c", "d", "e"]);
+///
+/// let endless = IterReader::new(std::iter::repeat("a".to_string()));
+/// let mut sread = StringReader::from(endless);
+/// sread.queue = VecDeque::from(["b".to_string()]);
+/// assert_eq!(sread.into_strings().size_hint(), (usize::MAX, None));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IterReader<I: Iterator<Item = String>> {
+    inner: I,
+    next: Option<String>,
+}
+
+impl<I: Iterator<Item = String>> IterReader<I> {
+    /// Create a reader popping the items of `inner`.
+    pub fn new(mut inner: I) -> Self {
+        let next = inner.next();
+        Self { inner, next }
+    }
+}
+
+impl<I: Iterator<Item = String>> StrRead for IterReader<I> {
+    fn peek_str(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => {
+                let (lower, upper) = self.inner.size_hint();
+                (
+                    lower.saturating_add(1),
+                    upper.and_then(|u| u.checked_add(1)),
+                )
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<I: Iterator<Item = String>> StringRead for IterReader<I> {
+    fn pop_string(&mut self) -> Option<String> {
+        let s = self.next.take()?;
+        self.next = self.inner.next();
+        Some(s)
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        self.next.as_mut()
+    }
+}
+
+/// A [`StringRead`] that pops whatever a closure returns, until it returns `None`.
+///
+/// One item is always generated ahead so that it can be peeked.
+///
+/// # Examples
+/// ```rust
+/// use std::cell::Cell;
+/// use string_reader::{FnReader, StrRead, StringRead};
+///
+/// let calls = Cell::new(0);
+/// let mut fread = FnReader::new(|| {
+///     let n = calls.get();
+///     calls.set(n + 1);
+///     (n < 3).then(|| format!("item {n}"))
+/// });
+/// assert_eq!(calls.get(), 1);
+/// assert_eq!(fread.peek_str(), Some("item 0"));
+/// assert_eq!(fread.peek_str(), Some("item 0"));
+/// assert_eq!(calls.get(), 1);
+/// assert_eq!(fread.pop_string(), Some("item 0".to_string()));
+/// assert_eq!(calls.get(), 2);
+/// assert_eq!(fread.pop_string(), Some("item 1".to_string()));
+/// assert_eq!(fread.pop_string(), Some("item 2".to_string()));
+/// assert_eq!(fread.pop_string(), None);
+/// assert_eq!(calls.get(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FnReader<F: FnMut() -> Option<String>> {
+    f: F,
+    next: Option<String>,
+}
+
+impl<F: FnMut() -> Option<String>> FnReader<F> {
+    /// Create a reader popping whatever `f` returns.
+    pub fn new(mut f: F) -> Self {
+        let next = f();
+        Self { f, next }
+    }
+}
+
+impl<F: FnMut() -> Option<String>> StrRead for FnReader<F> {
+    fn peek_str(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+}
+
+impl<F: FnMut() -> Option<String>> StringRead for FnReader<F> {
+    fn pop_string(&mut self) -> Option<String> {
+        let s = self.next.take()?;
+        self.next = (self.f)();
+        Some(s)
+    }
+
+    fn peek_mut_string(&mut self) -> Option<&mut String> {
+        self.next.as_mut()
+    }
+}
+
+/// A saved state of a [`StringReader`].
+///
+/// See [`StringReader::snapshot`].
+#[derive(Clone, Debug)]
+pub struct StringReaderSnapshot<R: StringRead = String> {
+    queue: VecDeque<String>,
+    reader: Option<Option<R>>,
+    stats: ConsumptionStats,
+    split: usize,
+}
+
+/// How many bytes a [`StringReader`] consumed through each kind of operation.
+///
+/// Moving content around without consuming it, like [`StringReader::ensure_contiguous`] does,
+/// isn't counted. Neither is editing the queue, directly or with methods like
+/// [`StringReader::truncate`].
+///
+/// See [`StringReader::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConsumptionStats {
+    /// Bytes removed as `String`s: whole segments popped with [`StringRead::pop_string`] and
+    /// the methods built on it, and spans taken by methods like [`StringReader::split_at_byte`]
+    /// or [`StringReader::consume_while_char`].
+    pub strings: u64,
+    /// Bytes removed one character or grapheme cluster at a time, like with
+    /// [`StringReader::pop_char`] or [`StringReader::advance_by`].
+    pub chars: u64,
+    /// Bytes read through [`std::io::Read`] and [`std::io::BufRead`].
+    pub read: u64,
+}
+
+/// Where [`std::io::Read`] stopped in the front segment `s`, given the saved `split`.
+///
+/// A real split always falls inside a character. Anything else means the front was changed
+/// since, so reading starts over at the beginning of `s`.
+fn read_offset(s: &str, split: usize) -> usize {
+    if split < s.len() && !s.is_char_boundary(split) {
+        split
+    } else {
+        0
+    }
+}
+
+/// Remove a trailing `\n` or `\r\n` from `line`.
+fn strip_line_ending(line: &mut String) {
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+}
+
+/// Which count of [`ConsumptionStats`] a consumption goes to.
+#[derive(Clone, Copy)]
+enum Consumed {
+    Strings,
+    Chars,
+    Read,
+}